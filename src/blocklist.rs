@@ -0,0 +1,41 @@
+// Profanity bundled with the `profanity-filter` feature, one word per line
+#[cfg(feature = "profanity-filter")]
+const PROFANITY: &str = include_str!("profanity.txt");
+
+// The words kept out of the filler when no list is given
+//
+// **Note:** This is empty unless the `profanity-filter` feature is enabled
+pub fn default_forbidden_words() -> Vec<String> {
+    #[cfg(feature = "profanity-filter")]
+    {
+        PROFANITY
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    }
+    #[cfg(not(feature = "profanity-filter"))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "profanity-filter")]
+    fn test_default_forbidden_words() {
+        let words = default_forbidden_words();
+        assert!(words.contains(&String::from("shit")));
+        assert!(words.iter().all(|word| !word.is_empty()));
+    }
+
+    #[test]
+    #[cfg(not(feature = "profanity-filter"))]
+    fn test_default_forbidden_words() {
+        assert!(default_forbidden_words().is_empty());
+    }
+}
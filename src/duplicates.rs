@@ -0,0 +1,113 @@
+use crate::normalize::Word;
+use crate::utils::letters;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conflict {
+    // Both words are placed with the same letters
+    Duplicate,
+    // The word can be read inside the other word
+    Substring,
+    // The word can be read backwards inside the other word
+    ReversedSubstring,
+}
+
+impl Conflict {
+    pub fn describe(&self, word: &str, other: &str) -> String {
+        match self {
+            Conflict::Duplicate => format!("Word {:?} is a duplicate of {:?}", word, other),
+            Conflict::Substring => format!("Word {:?} can be found inside {:?}", word, other),
+            Conflict::ReversedSubstring => {
+                format!("Word {:?} can be found backwards inside {:?}", word, other)
+            }
+        }
+    }
+}
+
+fn contains(haystack: &[&str], needle: &[&str]) -> bool {
+    needle.len() <= haystack.len()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+// Checks whether `word` can be read inside `other` in the grid
+pub fn find_conflict(word: &str, other: &str) -> Option<Conflict> {
+    let word = letters(word);
+    let other = letters(other);
+    if word == other {
+        return Some(Conflict::Duplicate);
+    }
+    if contains(&other, &word) {
+        return Some(Conflict::Substring);
+    }
+    let reversed: Vec<&str> = word.iter().rev().copied().collect();
+    if contains(&other, &reversed) {
+        return Some(Conflict::ReversedSubstring);
+    }
+    None
+}
+
+// Finds every word that is a duplicate of, or hidden inside, another word
+//
+// Returns the index of the word, the index of the word it conflicts
+// with and the kind of conflict, words that are hidden inside each other
+// such as duplicates are only reported for the later of the two words
+pub fn find_conflicts(words: &[Word]) -> Vec<(usize, usize, Conflict)> {
+    let mut conflicts = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        for (j, other) in words.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            match find_conflict(&word.text, &other.text) {
+                // Words hidden inside each other are only reported once
+                Some(_) if i < j && find_conflict(&other.text, &word.text).is_some() => {}
+                Some(conflict) => {
+                    conflicts.push((i, j, conflict));
+                    break;
+                }
+                None => {}
+            }
+        }
+    }
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize::Normalization;
+
+    #[test]
+    fn test_find_conflict() {
+        assert_eq!(find_conflict("art", "art"), Some(Conflict::Duplicate));
+        assert_eq!(find_conflict("art", "start"), Some(Conflict::Substring));
+        assert_eq!(
+            find_conflict("rats", "star"),
+            Some(Conflict::ReversedSubstring)
+        );
+        assert_eq!(
+            find_conflict("tra", "start"),
+            Some(Conflict::ReversedSubstring)
+        );
+        assert_eq!(find_conflict("start", "art"), None);
+        assert_eq!(find_conflict("car", "cart"), Some(Conflict::Substring));
+        assert_eq!(find_conflict("bus", "cart"), None);
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let words: Vec<Word> = ["start", "art", "ice cream", "icecream", "moon", "noom"]
+            .iter()
+            .map(|word| Word::new(word, &Normalization::default()))
+            .collect();
+        assert_eq!(
+            find_conflicts(&words),
+            vec![
+                (1, 0, Conflict::Substring),
+                (3, 2, Conflict::Duplicate),
+                (5, 4, Conflict::ReversedSubstring)
+            ]
+        );
+    }
+}
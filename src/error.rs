@@ -0,0 +1,146 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WordSearchError {
+    // No words were given to the puzzle
+    EmptyWordList,
+    // A word in the list has no letters
+    EmptyWord,
+    // A word is longer than the grid can be with a fixed size, a mask
+    // or a `max_width` and `max_height`
+    WordTooLong {
+        word: String,
+        length: i32,
+        max: i32,
+    },
+    // A word contains characters that cannot be placed in the grid
+    InvalidCharacters {
+        word: String,
+    },
+    // A word is a duplicate of, or can be read inside, another word
+    DuplicateWord {
+        word: String,
+        other: String,
+    },
+    // Two pinned words need different letters in the same cell
+    PinnedWordConflict {
+        word: String,
+        other: String,
+    },
+    // One of the settings has a value the generator cannot work with
+    InvalidSettings(String),
+    // A mask could not be read from the given text or image
+    InvalidMask(String),
+    // The words leave more blank cells than the hidden message has letters
+    MessageTooShort {
+        length: i32,
+        blanks: i32,
+    },
+    // No placement of the words left exactly as many blank cells as the
+    // hidden message has letters, and a bigger grid would leave too many
+    MessageBlanksNotReached {
+        length: i32,
+        width: i32,
+        height: i32,
+    },
+    // No grid was found within the allowed attempts and grid growths
+    GridGrowthExhausted {
+        width: i32,
+        height: i32,
+    },
+}
+
+impl WordSearchError {
+    // Stable identifier for the kind of error
+    //
+    // **Note:** Branch on this instead of the message, it does not change
+    pub fn code(&self) -> &'static str {
+        match self {
+            WordSearchError::EmptyWordList => "EMPTY_WORD_LIST",
+            WordSearchError::EmptyWord => "EMPTY_WORD",
+            WordSearchError::WordTooLong { .. } => "WORD_TOO_LONG",
+            WordSearchError::InvalidCharacters { .. } => "INVALID_CHARACTERS",
+            WordSearchError::DuplicateWord { .. } => "DUPLICATE_WORD",
+            WordSearchError::PinnedWordConflict { .. } => "PINNED_WORD_CONFLICT",
+            WordSearchError::InvalidSettings(_) => "INVALID_SETTINGS",
+            WordSearchError::InvalidMask(_) => "INVALID_MASK",
+            WordSearchError::MessageTooShort { .. } => "MESSAGE_TOO_SHORT",
+            WordSearchError::MessageBlanksNotReached { .. } => "MESSAGE_BLANKS_NOT_REACHED",
+            WordSearchError::GridGrowthExhausted { .. } => "GRID_GROWTH_EXHAUSTED",
+        }
+    }
+}
+
+impl fmt::Display for WordSearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WordSearchError::EmptyWordList => write!(f, "No words provided"),
+            WordSearchError::EmptyWord => write!(f, "Words cannot be empty"),
+            WordSearchError::WordTooLong { word, length, max } => write!(
+                f,
+                "Word {:?} has {} letters but the grid can be at most {} wide",
+                word, length, max
+            ),
+            WordSearchError::InvalidCharacters { word } => {
+                write!(
+                    f,
+                    "Word {:?} contains whitespace or control characters",
+                    word
+                )
+            }
+            WordSearchError::DuplicateWord { word, other } => write!(
+                f,
+                "Word {:?} is a duplicate of, or can be found inside, {:?}",
+                word, other
+            ),
+            WordSearchError::PinnedWordConflict { word, other } => write!(
+                f,
+                "Pinned word {:?} crosses {:?} with a different letter",
+                word, other
+            ),
+            WordSearchError::InvalidSettings(reason) => write!(f, "Invalid settings: {}", reason),
+            WordSearchError::InvalidMask(reason) => write!(f, "Invalid mask: {}", reason),
+            WordSearchError::MessageTooShort { length, blanks } => write!(
+                f,
+                "Hidden message has {} letters but at least {} cells are left blank",
+                length, blanks
+            ),
+            WordSearchError::MessageBlanksNotReached {
+                length,
+                width,
+                height,
+            } => write!(
+                f,
+                "No {}x{} grid found leaving exactly {} cells blank for the hidden message",
+                width, height, length
+            ),
+            WordSearchError::GridGrowthExhausted { width, height } => write!(
+                f,
+                "No valid {}x{} grid found and not allowed to grow more",
+                width, height
+            ),
+        }
+    }
+}
+
+impl Error for WordSearchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(WordSearchError::EmptyWordList.code(), "EMPTY_WORD_LIST");
+        let error = WordSearchError::GridGrowthExhausted {
+            width: 5,
+            height: 6,
+        };
+        assert_eq!(error.code(), "GRID_GROWTH_EXHAUSTED");
+        assert_eq!(
+            error.to_string(),
+            "No valid 5x6 grid found and not allowed to grow more"
+        );
+    }
+}
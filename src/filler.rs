@@ -0,0 +1,205 @@
+use crate::random::RandomSource;
+use crate::utils::letters;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillerMode {
+    // Every letter of the filler alphabet is equally likely
+    Uniform,
+    // Letters are drawn as often as they appear in the words
+    MatchWords,
+    // Letters of the filler alphabet are drawn as often as they
+    // appear in the language
+    Language(Language),
+}
+
+// Letter frequencies of a to z, in hundredths of a percent
+const ENGLISH: [u64; 26] = [
+    817, 149, 278, 425, 1270, 223, 202, 609, 697, 15, 77, 403, 241, 675, 751, 193, 10, 599, 633,
+    906, 276, 98, 236, 15, 197, 7,
+];
+const FRENCH: [u64; 26] = [
+    764, 90, 326, 367, 1472, 107, 87, 74, 753, 61, 5, 546, 297, 710, 580, 252, 136, 669, 795, 724,
+    631, 184, 5, 43, 13, 33,
+];
+const GERMAN: [u64; 26] = [
+    652, 189, 273, 508, 1640, 166, 301, 458, 655, 27, 142, 344, 253, 978, 259, 67, 2, 700, 728,
+    615, 435, 85, 192, 3, 4, 113,
+];
+const SPANISH: [u64; 26] = [
+    1153, 222, 402, 501, 1218, 69, 177, 70, 625, 49, 1, 497, 316, 671, 868, 251, 88, 687, 798, 463,
+    293, 114, 2, 22, 101, 47,
+];
+
+impl Language {
+    // Relative frequency of the letter, letters outside a to z
+    // are given the smallest weight so they still show up
+    pub fn frequency(&self, letter: &str) -> u64 {
+        let table = match self {
+            Language::English => &ENGLISH,
+            Language::French => &FRENCH,
+            Language::German => &GERMAN,
+            Language::Spanish => &SPANISH,
+        };
+        let mut chars = letter.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => {
+                table[(c.to_ascii_lowercase() as u8 - b'a') as usize]
+            }
+            _ => 1,
+        }
+    }
+}
+
+impl FillerMode {
+    // Name used for the mode outside of Rust
+    pub fn name(&self) -> &'static str {
+        match self {
+            FillerMode::Uniform => "uniform",
+            FillerMode::MatchWords => "matchWords",
+            FillerMode::Language(Language::English) => "english",
+            FillerMode::Language(Language::French) => "french",
+            FillerMode::Language(Language::German) => "german",
+            FillerMode::Language(Language::Spanish) => "spanish",
+        }
+    }
+
+    // Looks up a mode by its `name`
+    pub fn from_name(name: &str) -> Option<FillerMode> {
+        [
+            FillerMode::Uniform,
+            FillerMode::MatchWords,
+            FillerMode::Language(Language::English),
+            FillerMode::Language(Language::French),
+            FillerMode::Language(Language::German),
+            FillerMode::Language(Language::Spanish),
+        ]
+        .iter()
+        .find(|mode| mode.name() == name)
+        .copied()
+    }
+}
+
+// Draws the letters that fill the cells not taken by words
+pub struct Filler {
+    letters: Vec<String>,
+    // Running total of the letter weights
+    cumulative_weights: Vec<u64>,
+}
+
+impl Filler {
+    pub fn new(mode: FillerMode, alphabet: &str, words: &[String]) -> Filler {
+        let mut weights: Vec<(&str, u64)> = Vec::new();
+        match mode {
+            FillerMode::Uniform => {
+                for letter in letters(alphabet) {
+                    weights.push((letter, 1));
+                }
+            }
+            FillerMode::MatchWords => {
+                for letter in words.iter().flat_map(|word| letters(word)) {
+                    match weights.iter_mut().find(|(l, _)| *l == letter) {
+                        Some((_, weight)) => *weight += 1,
+                        None => weights.push((letter, 1)),
+                    }
+                }
+            }
+            FillerMode::Language(language) => {
+                for letter in letters(alphabet) {
+                    weights.push((letter, language.frequency(letter)));
+                }
+            }
+        }
+
+        let mut total = 0;
+        let mut filler = Filler {
+            letters: Vec::new(),
+            cumulative_weights: Vec::new(),
+        };
+        for (letter, weight) in weights.into_iter() {
+            total += weight;
+            filler.letters.push(String::from(letter));
+            filler.cumulative_weights.push(total);
+        }
+        filler
+    }
+
+    pub fn rand_letter(&self, rng: &mut dyn RandomSource) -> String {
+        let total = *self.cumulative_weights.last().unwrap();
+        let target = rng.gen_range(total as usize) as u64;
+        let index = self
+            .cumulative_weights
+            .iter()
+            .position(|weight| target < *weight)
+            .unwrap();
+        self.letters[index].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    fn count(filler: &Filler, letter: &str, draws: usize) -> usize {
+        let mut rng = Rng::new(5);
+        (0..draws)
+            .filter(|_| filler.rand_letter(&mut rng) == letter)
+            .count()
+    }
+
+    #[test]
+    fn test_uniform() {
+        let filler = Filler::new(FillerMode::Uniform, "ab", &[]);
+        let a = count(&filler, "a", 1000);
+        assert!(a > 400 && a < 600);
+    }
+
+    #[test]
+    fn test_match_words() {
+        let words = vec![String::from("aaab")];
+        let filler = Filler::new(FillerMode::MatchWords, "xyz", &words);
+        let a = count(&filler, "a", 1000);
+        assert!(a > 650 && a < 850);
+        assert_eq!(count(&filler, "x", 1000), 0);
+    }
+
+    #[test]
+    fn test_language() {
+        let filler = Filler::new(FillerMode::Language(Language::English), "EQ", &[]);
+        assert!(count(&filler, "E", 1000) > 950);
+        assert_eq!(Language::English.frequency("e"), 1270);
+        assert_eq!(Language::English.frequency("é"), 1);
+    }
+
+    #[test]
+    fn test_grapheme_alphabet() {
+        let filler = Filler::new(FillerMode::Uniform, "가나다", &[]);
+        let mut rng = Rng::new(1);
+        let mut i = 0;
+        while i < 20 {
+            assert!(["가", "나", "다"].contains(&filler.rand_letter(&mut rng).as_str()));
+            i += 1;
+        }
+    }
+
+    #[test]
+    fn test_filler_mode_names() {
+        assert_eq!(
+            FillerMode::from_name("german"),
+            Some(FillerMode::Language(Language::German))
+        );
+        assert_eq!(
+            FillerMode::from_name(FillerMode::MatchWords.name()),
+            Some(FillerMode::MatchWords)
+        );
+        assert_eq!(FillerMode::from_name("klingon"), None);
+    }
+}
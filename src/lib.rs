@@ -1,69 +1,274 @@
+mod blocklist;
+mod duplicates;
+mod error;
+mod filler;
 mod location;
+mod mask;
+mod normalize;
 mod orientations;
 mod position;
 mod puzzle;
+mod random;
+mod search;
 mod settings;
+mod statistics;
 mod utils;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-use js_sys::Math::{floor, random};
-use location::*;
-use orientations::*;
-use position::*;
-use puzzle::*;
-use settings::*;
+use duplicates::find_conflicts;
+use filler::Filler;
+use normalize::Word;
+use search::find_occurrences;
+use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use utils::*;
 
+pub use blocklist::default_forbidden_words;
+pub use error::*;
+pub use filler::{FillerMode, Language};
+pub use location::*;
+pub use mask::Mask;
+pub use normalize::{Accents, CaseFolding, Normalization};
+pub use orientations::*;
+pub use position::*;
+pub use puzzle::*;
+pub use random::*;
+pub use settings::*;
+pub use statistics::*;
+#[cfg(feature = "wasm")]
+pub use wasm::{JsRandom, WordSearchGenerator};
+
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
 #[cfg(feature = "wee_alloc")]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
-pub struct WordSearch<'a> {
-    words: Vec<String>,
+// The location of each placed word with the index of the word
+type Placements = Vec<(usize, Location)>;
+
+pub struct WordSearch {
+    words: Vec<Word>,
+    // Warnings found while checking the words, added to every puzzle
+    word_warnings: Vec<String>,
+    // Letters of the hidden message, normalized like the words
+    message: Vec<String>,
+    // Locations of the pinned words, which come first in `words`
+    pins: Vec<Location>,
     settings: Settings,
     max_word_len: i32,
+    requested_size: (i32, i32),
+    seed: u64,
+    rng: Box<dyn RandomSource>,
     pub grid_fill_percentage: f64,
+    pub statistics: Statistics,
     pub puzzle: Puzzle,
-    pub placed_words: HashMap<String, Location<'a>>,
+    pub placed_words: HashMap<String, Location>,
     pub unplaced_words: Vec<String>,
 }
 
-impl<'a> WordSearch<'a> {
-    fn fill_puzzle(&mut self) -> Option<Vec<Vec<char>>> {
-        let mut puzzle =
-            vec![vec![' '; self.settings.width as usize]; self.settings.height as usize];
-        let mut i: usize = 0;
-        while i < self.words.len() {
-            if self.place_word_in_puzzle(&self.words[i], &mut puzzle) {
-                i += 1;
-            } else {
-                self.puzzle.words_not_placed.push(self.words[i].clone());
-                return None;
+impl WordSearch {
+    // Places the words longest first, when a word has nowhere to go the
+    // placements back to the latest word in its way are undone and that
+    // word is moved to its next best location
+    //
+    // **Note:** Gives up once the `budget` of placements that can be undone
+    // at this grid size runs out and returns the index of the word that
+    // could not be placed, or `None` when there are no words to place and
+    // the cells left blank do not match the hidden message
+    fn fill_puzzle(
+        &mut self,
+        dropped: &[usize],
+        budget: &mut i32,
+    ) -> Result<(Grid, Placements), Option<usize>> {
+        let mut puzzle: Grid =
+            vec![vec![String::new(); self.settings.width as usize]; self.settings.height as usize];
+        for pin in self.pins.iter() {
+            self.place_word(&pin.word, pin, &mut puzzle);
+        }
+        let mut order: Vec<usize> = (self.pins.len()..self.words.len())
+            .filter(|i| !dropped.contains(i))
+            .collect();
+        let blanks = self.usable_cells()
+            - puzzle
+                .iter()
+                .flatten()
+                .filter(|cell| !cell.is_empty())
+                .count() as i32;
+        if order.is_empty() {
+            if !self.message.is_empty() && blanks != self.message.len() as i32 {
+                return Err(None);
             }
+            return Ok((puzzle, self.pins.iter().cloned().enumerate().collect()));
         }
-        Some(puzzle)
+        order.sort_by_key(|&i| Reverse(letters(&self.words[i].text).len()));
+
+        // Each placed word with its location, the cells it filled in and
+        // the other locations left to try for it
+        let mut placed: Vec<(Location, Vec<Position>, Vec<Location>)> = Vec::new();
+        // For the word at each depth, the earlier words that kept the
+        // words after it from being placed while it was moved
+        let mut conflicts: Vec<Vec<usize>> = vec![Vec::new()];
+        // Letters of the words from each one in the order to the last
+        let mut remaining: Vec<i32> = order
+            .iter()
+            .rev()
+            .scan(0, |total, &i| {
+                *total += letters(&self.words[i].text).len() as i32;
+                Some(*total)
+            })
+            .collect();
+        remaining.reverse();
+        remaining.push(0);
+        // Letters the words still have to share to leave exactly
+        // as many blank cells as the hidden message has letters
+        let mut needed = remaining[0] - (blanks - self.message.len() as i32);
+        let mut candidates = self.candidate_locations(order[0], &puzzle);
+        self.steer_overlap(&mut candidates, needed, order.len());
+        while placed.len() < order.len() {
+            let location = match candidates.pop() {
+                Some(location) => location,
+                None => {
+                    // Jump back to the latest word in the way of this word or
+                    // of the words that failed after it
+                    let failed = order[placed.len()];
+                    let mut culprits = conflicts.pop().unwrap();
+                    if self.message.is_empty() {
+                        let blocked = self.blocked_cells(failed, &puzzle);
+                        culprits.extend(
+                            placed
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, (_, cells, _))| {
+                                    cells.iter().any(|cell| blocked.contains(cell))
+                                })
+                                .map(|(j, _)| j),
+                        );
+                    } else {
+                        // Every earlier word changes the blanks left for the message
+                        culprits.extend(0..placed.len());
+                    }
+                    let target = match culprits.iter().max() {
+                        Some(&target) if (placed.len() - target) as i32 <= *budget => target,
+                        _ => return Err(Some(failed)),
+                    };
+                    while placed.len() > target {
+                        let (location, cells, rest) = placed.pop().unwrap();
+                        for cell in cells.iter() {
+                            puzzle[cell.y as usize][cell.x as usize].clear();
+                        }
+                        needed += location.overlap;
+                        candidates = rest;
+                        *budget -= 1;
+                        self.statistics.backtracks += 1;
+                    }
+                    conflicts.truncate(target + 1);
+                    for culprit in culprits {
+                        if culprit < target && !conflicts[target].contains(&culprit) {
+                            conflicts[target].push(culprit);
+                        }
+                    }
+                    continue;
+                }
+            };
+            // The words after this one must be able to share the letters
+            // still needed for the hidden message, and no more
+            if !self.message.is_empty() {
+                let left = needed - location.overlap;
+                if left < 0 || left > remaining[placed.len() + 1] {
+                    continue;
+                }
+            }
+            needed -= location.overlap;
+            let cells = self.place_word(&location.word, &location, &mut puzzle);
+            placed.push((location, cells, candidates));
+            conflicts.push(Vec::new());
+            candidates = match order.get(placed.len()) {
+                Some(&i) => self.candidate_locations(i, &puzzle),
+                None => Vec::new(),
+            };
+            self.steer_overlap(&mut candidates, needed, order.len() - placed.len());
+        }
+
+        // Placements are kept in the order the words were given
+        let mut placements: Placements = self
+            .pins
+            .iter()
+            .cloned()
+            .enumerate()
+            .chain(
+                order
+                    .into_iter()
+                    .zip(placed.into_iter().map(|(location, _, _)| location)),
+            )
+            .collect();
+        placements.sort_by_key(|(i, _)| *i);
+        Ok((puzzle, placements))
     }
 
-    fn place_word_in_puzzle(&self, word: &str, puzzle: &mut Vec<Vec<char>>) -> bool {
-        let locations = self.find_best_locations(word, puzzle);
-        if locations.len() == 0 {
-            return false;
+    // Every location the `i`th word fits in, in random order with the
+    // locations best matching the overlap policy last
+    fn candidate_locations(&mut self, i: usize, puzzle: &[Vec<String>]) -> Vec<Location> {
+        let mut locations = self.find_locations(&self.words[i].text, puzzle);
+        self.shuffle(&mut locations);
+        match self.settings.overlap {
+            OverlapPolicy::Prefer | OverlapPolicy::Disallow => {
+                locations.sort_by_key(|location| location.overlap)
+            }
+            OverlapPolicy::Avoid => locations.sort_by_key(|location| Reverse(location.overlap)),
         }
-        let selected_location: Location =
-            locations[floor(random() * locations.len() as f64) as usize];
-        self.place_word(word, selected_location, puzzle);
-        true
+        locations
     }
 
-    fn find_best_locations(&'a self, word: &'a str, puzzle: &Vec<Vec<char>>) -> Vec<Location> {
+    // Moves the locations sharing closest to an even part of the `needed`
+    // letters among the words `left` to the end, when there is a hidden message
+    fn steer_overlap(&self, locations: &mut [Location], needed: i32, left: usize) {
+        if self.message.is_empty() || left == 0 {
+            return;
+        }
+        let left = left as i32;
+        locations.sort_by_key(|location| Reverse((location.overlap * left - needed).abs()));
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        let mut n = items.len();
+        while n > 1 {
+            let j = self.rng.gen_range(n);
+            items.swap(n - 1, j);
+            n -= 1;
+        }
+    }
+
+    fn find_locations(&self, word: &str, puzzle: &[Vec<String>]) -> Vec<Location> {
         let mut locations: Vec<Location> = Vec::new();
-        let mut pruned_locations: Vec<Location> = Vec::new();
+        let word_letters = letters(word);
+        let len = word_letters.len() as i32;
+        for (x, y, orientation) in self.start_cells(len) {
+            let cells = word_cells(x, y, len, &orientation, self.wrap());
+            let overlap = self.overlap(&cells, &word_letters, puzzle);
+            // A word written entirely over other letters would not be
+            // its own word in the grid
+            let allowed = match self.settings.overlap {
+                OverlapPolicy::Disallow => overlap == 0,
+                _ => overlap >= 0 && overlap < len,
+            };
+            if allowed {
+                let mut location = self.location(x, y, orientation, word);
+                location.overlap = overlap;
+                locations.push(location);
+            }
+        }
+        locations
+    }
+
+    // Every cell and orientation a word of `len` letters can start at
+    // in an empty grid of the current size
+    fn start_cells(&self, len: i32) -> Vec<(i32, i32, Orientation)> {
+        let mut starts: Vec<(i32, i32, Orientation)> = Vec::new();
         let height: i32 = self.settings.height;
         let width: i32 = self.settings.width;
-        let word_len = word.len() as i32;
-        let mut max_overlap: i32 = 0;
 
         for orientation in self.settings.orientations.iter() {
             let skip = skip_orientation(orientation);
@@ -71,204 +276,824 @@ impl<'a> WordSearch<'a> {
             let mut x: i32 = 0;
             let mut y: i32 = 0;
             while y < height {
-                if check(x, y, height, width, word_len) {
-                    let overlap: i32 = self.calculate_overlap(word, puzzle, x, y, orientation);
-                    if overlap >= max_overlap {
-                        max_overlap = overlap;
-                        locations.push(Location::from(x, y, overlap, orientation, word));
-                    }
+                // Every start cell works when words wrap around the grid
+                if self.settings.wrap_around || check(x, y, height, width, len) {
+                    starts.push((x, y, *orientation));
                     x += 1;
                     if x >= width {
                         x = 0;
                         y += 1;
                     }
                 } else {
-                    let next_possible: Position = skip(x, y, word_len);
+                    let next_possible: Position = skip(x, y, len);
                     x = next_possible.x;
                     y = next_possible.y;
+                    if x >= width {
+                        x = 0;
+                        y += 1;
+                    }
                 }
             }
         }
+        starts
+    }
 
-        for location in locations.iter() {
-            if location.overlap >= max_overlap {
-                pruned_locations.push(location.clone());
+    // The cells holding letters that keep the `i`th word out
+    // of the locations it would otherwise fit in
+    fn blocked_cells(&self, i: usize, puzzle: &[Vec<String>]) -> HashSet<Position> {
+        let mut blocked: HashSet<Position> = HashSet::new();
+        let word_letters = letters(&self.words[i].text);
+        let len = word_letters.len() as i32;
+        for (x, y, orientation) in self.start_cells(len) {
+            let cells = word_cells(x, y, len, &orientation, self.wrap());
+            if (self.settings.wrap_around && repeats_cells(&cells))
+                || cells.iter().any(|cell| !self.is_usable(cell.x, cell.y))
+            {
+                continue;
+            }
+            for (cell, letter) in cells.into_iter().zip(word_letters.iter()) {
+                let current = &puzzle[cell.y as usize][cell.x as usize];
+                if !current.is_empty()
+                    && (current != letter || self.settings.overlap == OverlapPolicy::Disallow)
+                {
+                    blocked.insert(cell);
+                }
             }
         }
-        pruned_locations
+        blocked
     }
 
-    fn calculate_overlap(
-        &self,
-        word: &str,
-        puzzle: &Vec<Vec<char>>,
-        x: i32,
-        y: i32,
-        orientation: &Orientation,
-    ) -> i32 {
-        let next_orientation = get_orientation(orientation);
+    // Width and height of the grid words wrap around,
+    // `None` unless `Settings::wrap_around` is set
+    fn wrap(&self) -> Option<(i32, i32)> {
+        if self.settings.wrap_around {
+            Some((self.settings.width, self.settings.height))
+        } else {
+            None
+        }
+    }
+
+    // Location of a word starting at `x`, `y`, wrapping around
+    // the grid when `Settings::wrap_around` is set
+    fn location(&self, x: i32, y: i32, orientation: Orientation, word: &str) -> Location {
+        Location {
+            wrap: self.wrap(),
+            ..Location::from(x, y, 0, orientation, word)
+        }
+    }
+
+    // The number of letters the word shares with the words already in
+    // the puzzle, or -1 when it cannot be placed at the location
+    fn calculate_overlap(&self, location: &Location, puzzle: &[Vec<String>]) -> i32 {
+        self.overlap(&location.cells(), &letters(&location.word), puzzle)
+    }
+
+    // The number of `letters` written in the `cells` of the puzzle already,
+    // or -1 when another letter or the mask is in the way
+    fn overlap(&self, cells: &[Position], letters: &[&str], puzzle: &[Vec<String>]) -> i32 {
+        if self.settings.wrap_around && repeats_cells(cells) {
+            return -1;
+        }
         let mut overlap: i32 = 0;
-        let mut i: i32 = 0;
-        while i < word.len() as i32 {
-            let position: Position = next_orientation(x, y, i);
-            match puzzle[position.y as usize][position.x as usize] {
-                ' ' => {}
-                c => {
-                    if c == word.chars().nth(i as usize).unwrap() {
-                        overlap += 1;
-                    } else {
-                        return -1;
-                    }
+        for (i, position) in cells.iter().enumerate() {
+            if !self.is_usable(position.x, position.y) {
+                return -1;
+            }
+            let cell = &puzzle[position.y as usize][position.x as usize];
+            if !cell.is_empty() {
+                if cell == letters[i] {
+                    overlap += 1;
+                } else {
+                    return -1;
                 }
             }
-            i += 1;
         }
         overlap
     }
 
-    fn place_word(&self, word: &str, location: Location, puzzle: &mut Vec<Vec<char>>) {
-        let orientation = location.orientation;
-        let next_orientation = get_orientation(orientation);
-        let mut i: i32 = 0;
-        while i < word.len() as i32 {
-            let position: Position = next_orientation(location.x, location.y, i);
-            match puzzle[position.y as usize][position.x as usize] {
-                ' ' => {
-                    puzzle[position.y as usize][position.x as usize] =
-                        word.chars().nth(i as usize).unwrap();
-                }
-                _ => {}
+    // Writes the word into the puzzle and returns the cells that were blank
+    fn place_word(
+        &self,
+        word: &str,
+        location: &Location,
+        puzzle: &mut [Vec<String>],
+    ) -> Vec<Position> {
+        let letters = letters(word);
+        let mut filled: Vec<Position> = Vec::new();
+        for (i, position) in location.cells().into_iter().enumerate() {
+            let cell = &mut puzzle[position.y as usize][position.x as usize];
+            if cell.is_empty() {
+                *cell = String::from(letters[i]);
+                filled.push(position);
             }
-            i += 1;
         }
+        filled
     }
 
-    fn fill_blanks(&self, puzzle: &mut Vec<Vec<char>>) -> (i32, Vec<Vec<char>>) {
+    fn filler(&self) -> Filler {
+        let texts: Vec<String> = self.words.iter().map(|word| word.text.clone()).collect();
+        Filler::new(
+            self.settings.filler_mode,
+            &self.settings.filler_alphabet,
+            &texts,
+        )
+    }
+
+    // Whether letters can go in the cell, every cell is usable without a mask
+    fn is_usable(&self, x: i32, y: i32) -> bool {
+        self.settings
+            .mask
+            .as_ref()
+            .is_none_or(|mask| mask.is_usable(x, y))
+    }
+
+    // The number of cells letters can go in
+    fn usable_cells(&self) -> i32 {
+        match &self.settings.mask {
+            Some(mask) => mask.usable_cells(),
+            None => self.settings.width * self.settings.height,
+        }
+    }
+
+    // Fills the blank cells with the hidden message in reading order,
+    // or with random filler letters when there is no message
+    fn fill_blanks(&mut self, puzzle: &mut [Vec<String>]) -> (i32, Grid) {
+        let filler = self.filler();
+        let mut message = self.message.iter();
         let mut extra_letters_count: i32 = 0;
         let mut i: usize = 0;
         while i < puzzle.len() {
             let mut j: usize = 0;
-            while j < puzzle[i as usize].len() {
-                if puzzle[i][j] == ' ' {
-                    puzzle[i][j] = rand_char();
+            while j < puzzle[i].len() {
+                if puzzle[i][j].is_empty() && self.is_usable(j as i32, i as i32) {
+                    puzzle[i][j] = match message.next() {
+                        Some(letter) => letter.clone(),
+                        None => filler.rand_letter(self.rng.as_mut()),
+                    };
                     extra_letters_count += 1;
                 }
                 j += 1;
             }
             i += 1;
         }
-        (extra_letters_count, puzzle.clone())
+        if extra_letters_count != self.message.len() as i32 && !self.message.is_empty() {
+            self.puzzle.add_warning(&format!(
+                "The hidden message has {} letters but {} cells were left blank",
+                self.message.len(),
+                extra_letters_count
+            ));
+        }
+        (extra_letters_count, puzzle.to_vec())
     }
 
     fn update_size(&mut self, w: i32, h: i32) {
-        let m = self.max_word_len;
-        let width = if m as i32 > w { m as i32 } else { w };
-        let height = if m as i32 > h { m as i32 } else { h };
+        let (width, height) = if !self.settings.can_grow() {
+            (w, h)
+        } else {
+            // Make room for the longest word without going over the limits
+            let m = self.max_word_len;
+            (
+                w.max(m).min(self.settings.max_width.unwrap_or(i32::MAX)),
+                h.max(m).min(self.settings.max_height.unwrap_or(i32::MAX)),
+            )
+        };
 
         self.settings.set_width(width);
         self.settings.set_height(height);
         self.puzzle = Puzzle::new(self.settings.width, self.settings.height);
+        self.puzzle.warnings = self.word_warnings.clone();
         self.grid_fill_percentage = 0.0;
     }
 
-    pub fn new(words: &Vec<String>, w: i32, h: i32) -> WordSearch {
-        set_panic_hook();
+    // The cells left blank in a grid of `usable` cells when the words
+    // other than the `skipped` ones share no letters
+    fn min_blanks(&self, usable: i32, skipped: &[usize]) -> i32 {
+        let letter_count: i32 = (0..self.words.len())
+            .filter(|i| !skipped.contains(i))
+            .map(|i| letters(&self.words[i].text).len() as i32)
+            .sum();
+        usable - letter_count
+    }
 
-        if words.len() == 0 {
-            panic!("No words provided");
+    // The next bigger grid size, if the grid is still allowed to grow
+    fn next_size(&self) -> Option<(i32, i32)> {
+        if !self.settings.can_grow()
+            || self.statistics.grid_growths >= self.settings.max_grid_growth
+        {
+            return None;
         }
+        let width = self.settings.width + 1;
+        let height = self.settings.height + 1;
+        let next = (
+            width.min(self.settings.max_width.unwrap_or(width)),
+            height.min(self.settings.max_height.unwrap_or(height)),
+        );
+        if next == (self.settings.width, self.settings.height) {
+            None
+        } else {
+            Some(next)
+        }
+    }
+
+    // Shrinks the grid while the words would still cover
+    // `Settings::target_density` of it, then picks words at random until
+    // they cover it and returns the indices of the words left out
+    fn apply_target_density(&mut self) -> Vec<usize> {
+        let target = match self.settings.target_density {
+            Some(target) => target,
+            None => return Vec::new(),
+        };
+        let lengths: Vec<i32> = self
+            .words
+            .iter()
+            .map(|word| letters(&word.text).len() as i32)
+            .collect();
+        let total: i32 = lengths.iter().sum();
 
-        let mut word_list: Vec<String> = words.clone();
-        word_list.sort_by(|a, b| a.len().cmp(&b.len()));
-        let m: i32 = word_list.last().unwrap().len() as i32;
-        let width = if m as i32 > w { m as i32 } else { w };
-        let height = if m as i32 > h { m as i32 } else { h };
+        if self.settings.can_grow() {
+            let m = self.max_word_len;
+            // The grid cannot shrink past the pinned words
+            let cells: Vec<Position> = self.pins.iter().flat_map(|pin| pin.cells()).collect();
+            let min_width = cells
+                .iter()
+                .map(|cell| cell.x + 1)
+                .max()
+                .unwrap_or(m)
+                .max(m);
+            let min_height = cells
+                .iter()
+                .map(|cell| cell.y + 1)
+                .max()
+                .unwrap_or(m)
+                .max(m);
+            let mut width = self.settings.width;
+            let mut height = self.settings.height;
+            loop {
+                let (w, h) = if width >= height {
+                    (width - 1, height)
+                } else {
+                    (width, height - 1)
+                };
+                if w < min_width || h < min_height || f64::from(total) > target * f64::from(w * h) {
+                    break;
+                }
+                width = w;
+                height = h;
+            }
+            self.update_size(width, height);
+        }
+
+        let capacity = target * f64::from(self.usable_cells());
+        let mut unused: Vec<usize> = Vec::new();
+        if f64::from(total) <= capacity {
+            return unused;
+        }
+        // Pinned words are always used
+        let mut used: i32 = lengths[..self.pins.len()].iter().sum();
+        let mut order: Vec<usize> = (self.pins.len()..self.words.len()).collect();
+        self.shuffle(&mut order);
+        for i in order {
+            // Always keep at least one word
+            if used == 0 || f64::from(used + lengths[i]) <= capacity {
+                used += lengths[i];
+            } else {
+                unused.push(i);
+            }
+        }
+        unused.sort_unstable();
+        unused
+    }
 
-        let word_search = WordSearch {
-            words: words.clone(),
-            settings: Settings {
-                width,
-                height,
+    pub fn new(words: &[String], w: i32, h: i32) -> Result<WordSearch, WordSearchError> {
+        WordSearch::with_settings(
+            words,
+            Settings {
+                width: w,
+                height: h,
                 ..Settings::default()
             },
+        )
+    }
+
+    pub fn with_settings(
+        words: &[String],
+        settings: Settings,
+    ) -> Result<WordSearch, WordSearchError> {
+        set_panic_hook();
+
+        if words.is_empty() && settings.pinned_words.is_empty() {
+            return Err(WordSearchError::EmptyWordList);
+        }
+        settings.validate()?;
+        let mut settings = settings;
+        if let Some(mask) = &settings.mask {
+            settings.width = mask.width();
+            settings.height = mask.height();
+        }
+        let pinned_count = settings.pinned_words.len();
+        let mut words: Vec<Word> = settings
+            .pinned_words
+            .iter()
+            .map(|pin| &pin.word)
+            .chain(words.iter())
+            .map(|word| Word::new(word, &settings.normalization))
+            .collect();
+        for word in words.iter() {
+            if word.text.is_empty() {
+                return Err(WordSearchError::EmptyWord);
+            }
+            if word
+                .text
+                .chars()
+                .any(|c| c.is_whitespace() || c.is_control())
+            {
+                return Err(WordSearchError::InvalidCharacters {
+                    word: word.display.clone(),
+                });
+            }
+        }
+
+        let mut word_warnings: Vec<String> = Vec::new();
+        let conflicts = find_conflicts(&words);
+        match settings.duplicates {
+            DuplicatePolicy::Fail => {
+                if let Some((i, j, _)) = conflicts.first() {
+                    return Err(WordSearchError::DuplicateWord {
+                        word: words[*i].display.clone(),
+                        other: words[*j].display.clone(),
+                    });
+                }
+            }
+            DuplicatePolicy::Warn | DuplicatePolicy::Drop => {
+                // Pinned words are never dropped, words given twice are always
+                // dropped as the answer key has one entry per word
+                let mut dropped: Vec<usize> = Vec::new();
+                for (i, j, conflict) in conflicts.iter() {
+                    let k = if *i < pinned_count { *j } else { *i };
+                    let drop = settings.duplicates == DuplicatePolicy::Drop
+                        || words[*i].display == words[*j].display;
+                    if !drop {
+                        word_warnings
+                            .push(conflict.describe(&words[*i].display, &words[*j].display));
+                    } else if k >= pinned_count && !dropped.contains(&k) {
+                        word_warnings.push(format!(
+                            "{}, {:?} was left out",
+                            conflict.describe(&words[*i].display, &words[*j].display),
+                            words[k].display
+                        ));
+                        dropped.push(k);
+                    }
+                }
+                let mut i = 0;
+                words.retain(|_| {
+                    i += 1;
+                    !dropped.contains(&(i - 1))
+                });
+            }
+        }
+
+        let mut word_list: Vec<&Word> = words.iter().collect();
+        word_list.sort_by_key(|word| letters(&word.text).len());
+        let m: i32 = letters(&word_list.last().unwrap().text).len() as i32;
+        // The grid widens to fit the longest word unless its size is limited
+        if let Some((max_width, max_height)) = settings.max_size() {
+            let max_size = max_width.max(max_height);
+            if m > max_size {
+                return Err(WordSearchError::WordTooLong {
+                    word: word_list.last().unwrap().display.clone(),
+                    length: m,
+                    max: max_size,
+                });
+            }
+        }
+        let message: Vec<String> = match &settings.hidden_message {
+            Some(message) => {
+                let text = settings.normalization.apply(message);
+                if text.is_empty() || text.chars().any(|c| c.is_whitespace() || c.is_control()) {
+                    return Err(WordSearchError::InvalidSettings(String::from(
+                        "hidden_message must have letters and no whitespace once normalized",
+                    )));
+                }
+                letters(&text).into_iter().map(String::from).collect()
+            }
+            None => Vec::new(),
+        };
+        let seed = settings.seed.unwrap_or_else(random_seed);
+        let (width, height) = (settings.width, settings.height);
+
+        let mut word_search = WordSearch {
+            words,
+            word_warnings,
+            message,
+            pins: Vec::new(),
+            settings,
             puzzle: Puzzle::new(width, height),
             grid_fill_percentage: 0.0,
+            statistics: Statistics::default(),
             max_word_len: m,
+            requested_size: (width, height),
+            seed,
+            rng: Box::new(Rng::new(seed)),
             placed_words: HashMap::new(),
             unplaced_words: Vec::new(),
         };
-        word_search
+        // Pins are checked against the grid widened for the longest word
+        word_search.update_size(width, height);
+        word_search.pin_words()?;
+        Ok(word_search)
+    }
+
+    // Finds the locations of the pinned words and checks they fit
+    // in the grid and agree on the letters of the cells they share
+    fn pin_words(&mut self) -> Result<(), WordSearchError> {
+        let mut puzzle: Grid =
+            vec![vec![String::new(); self.settings.width as usize]; self.settings.height as usize];
+        let mut i = 0;
+        while i < self.settings.pinned_words.len() {
+            let pin = &self.settings.pinned_words[i];
+            let text = self.words[i].text.clone();
+            let mut location = Location::from(pin.x, pin.y, 0, pin.orientation, &text);
+            let cells = location.cells();
+            if cells.iter().any(|cell| {
+                cell.x < 0
+                    || cell.y < 0
+                    || cell.x >= self.settings.width
+                    || cell.y >= self.settings.height
+                    || !self.is_usable(cell.x, cell.y)
+            }) {
+                return Err(WordSearchError::InvalidSettings(format!(
+                    "pinned word {:?} does not fit in the grid",
+                    self.words[i].display
+                )));
+            }
+            location.overlap = self.calculate_overlap(&location, &puzzle);
+            if location.overlap < 0 {
+                // Find the earlier pinned word with a different letter in a shared cell
+                let own: Vec<(Position, &str)> =
+                    cells.iter().copied().zip(letters(&text)).collect();
+                let other = self.pins.iter().position(|other| {
+                    other
+                        .cells()
+                        .into_iter()
+                        .zip(letters(&other.word))
+                        .any(|(cell, letter)| {
+                            own.iter().any(|(own_cell, own_letter)| {
+                                *own_cell == cell && *own_letter != letter
+                            })
+                        })
+                });
+                return Err(WordSearchError::PinnedWordConflict {
+                    word: self.words[i].display.clone(),
+                    other: self.words[other.unwrap_or(0)].display.clone(),
+                });
+            }
+            self.place_word(&text, &location, &mut puzzle);
+            self.pins.push(location);
+            i += 1;
+        }
+        Ok(())
     }
 
-    pub fn create(&mut self) -> &mut WordSearch<'a> {
+    // Replaces the seeded generator with a custom source of randomness
+    //
+    // **Note:** The puzzle is only reproducible if the source is
+    pub fn with_random_source(
+        words: &[String],
+        settings: Settings,
+        rng: Box<dyn RandomSource>,
+    ) -> Result<WordSearch, WordSearchError> {
+        Ok(WordSearch {
+            rng,
+            ..WordSearch::with_settings(words, settings)?
+        })
+    }
+
+    // The seed used to generate the puzzle
+    //
+    // **Note:** Store this to recreate an unseeded puzzle later on
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn create(&mut self) -> Result<&mut WordSearch, WordSearchError> {
         set_panic_hook();
 
-        let mut puzzle: Option<Vec<Vec<char>>> = None;
+        // Restart the sequence so every call with the same seed and
+        // settings creates the same puzzle
+        self.update_size(self.requested_size.0, self.requested_size.1);
+        self.rng.reseed(self.seed);
+
+        let started = now_ms();
+        self.statistics = Statistics::default();
+        let unused = self.apply_target_density();
         let mut attempts = 0;
-        let mut grid_growths = 0;
+        // Placements that can still be undone at this grid size
+        let mut budget = self.settings.max_backtracks;
+        // Words left out of a grid that cannot grow
+        let mut dropped: Vec<usize> = Vec::new();
 
         loop {
             attempts += 1;
+            self.puzzle.words_not_placed = Vec::new();
 
-            if attempts < self.settings.max_attempts {
-                self.puzzle.words_not_placed = Vec::new();
-                puzzle = self.fill_puzzle();
+            let skipped: Vec<usize> = dropped.iter().chain(unused.iter()).copied().collect();
+            if !self.message.is_empty() {
+                let blanks = self.min_blanks(self.usable_cells(), &skipped);
+                if blanks > self.message.len() as i32 {
+                    self.finish_statistics(started);
+                    return Err(WordSearchError::MessageTooShort {
+                        length: self.message.len() as i32,
+                        blanks,
+                    });
+                }
             }
-
-            match puzzle {
-                None => {
-                    grid_growths += 1;
-
-                    if grid_growths > self.settings.max_grid_growth {
-                        self.puzzle.add_errors(&format!(
-                            "No valid {}x{} grid found and not allowed to grow more",
-                            self.settings.width, self.settings.height
-                        ));
-                        return self;
+            match self.fill_puzzle(&skipped, &mut budget) {
+                Err(failed) => {
+                    // Retry at the same size until the attempts run out
+                    if attempts < self.settings.max_attempts {
+                        continue;
                     }
 
-                    // Trying a bigger grid after nth attempts
-                    self.update_size(self.settings.width + 1, self.settings.height + 1);
+                    self.statistics.add_attempts(
+                        self.settings.width,
+                        self.settings.height,
+                        attempts,
+                    );
                     attempts = 0;
+                    // A bigger grid or fewer words only leave more cells blank,
+                    // which cannot help once that is more than the message needs
+                    let message_fits = |usable: i32, skipped: &[usize]| {
+                        self.message.is_empty()
+                            || self.min_blanks(usable, skipped) <= self.message.len() as i32
+                    };
+                    let can_grow = self
+                        .next_size()
+                        .is_some_and(|(width, height)| message_fits(width * height, &skipped));
+                    let without_failed: Vec<usize> =
+                        skipped.iter().copied().chain(failed).collect();
+                    let can_drop = (self.settings.fixed_size || self.settings.best_effort)
+                        && failed.is_some()
+                        && message_fits(self.usable_cells(), &without_failed);
+                    match self.next_size() {
+                        Some((width, height)) if can_grow => {
+                            // Trying a bigger grid after nth attempts
+                            self.statistics.grid_growths += 1;
+                            self.update_size(width, height);
+                            budget = self.settings.max_backtracks;
+                        }
+                        _ if can_drop => {
+                            // Leave out the word that did not fit and try again
+                            dropped.extend(failed);
+                        }
+                        _ => {
+                            if let Some(failed) = failed {
+                                let display = self.words[failed].display.clone();
+                                self.puzzle.words_not_placed.push(display);
+                            }
+                            self.finish_statistics(started);
+                            if !self.message.is_empty() {
+                                return Err(WordSearchError::MessageBlanksNotReached {
+                                    length: self.message.len() as i32,
+                                    width: self.settings.width,
+                                    height: self.settings.height,
+                                });
+                            }
+                            return Err(WordSearchError::GridGrowthExhausted {
+                                width: self.settings.width,
+                                height: self.settings.height,
+                            });
+                        }
+                    }
                 }
-                Some(mut p) => {
-                    let (extra_letters_count, p) = self.fill_blanks(&mut p);
-                    self.grid_fill_percentage = (1.0
-                        - extra_letters_count as f64
-                            / (self.settings.width * self.settings.height) as f64)
-                        * 100.0;
+                Ok((mut p, placements)) => {
+                    self.statistics.add_attempts(
+                        self.settings.width,
+                        self.settings.height,
+                        attempts,
+                    );
+                    let (extra_letters_count, mut p) = self.fill_blanks(&mut p);
+                    if self.settings.unique_words || !self.settings.forbidden_words.is_empty() {
+                        self.clean_filler(&mut p, &placements);
+                    }
+                    self.grid_fill_percentage =
+                        (1.0 - extra_letters_count as f64 / self.usable_cells() as f64) * 100.0;
                     self.puzzle.puzzle = p;
-                    // self.solve();
-                    return self;
+                    if !dropped.is_empty() {
+                        let words = self.words.len() - unused.len();
+                        self.puzzle.add_warning(&format!(
+                            "Only {} of {} words fit in the puzzle",
+                            words - dropped.len(),
+                            words
+                        ));
+                    }
+                    dropped.sort_unstable();
+                    for i in dropped {
+                        let display = self.words[i].display.clone();
+                        self.puzzle.words_not_placed.push(display);
+                    }
+                    for &i in unused.iter() {
+                        let display = self.words[i].display.clone();
+                        self.puzzle.words_not_used.push(display);
+                    }
+                    self.record_placements(placements, &skipped);
+                    self.finish_statistics(started);
+                    return Ok(self);
                 }
             }
         }
     }
 
-    // fn solve(&mut self) {
-    //     // Solving the puzzle
-    //     let p_copy = self.puzzle.puzzle.clone();
-    //     let mut i = 0;
-    //     while i < self.words.len() {
-    //         let word = self.words[i].as_str();
-    //         let locations = self.find_best_locations(word, &p_copy);
-    //         if locations.len() != 0 {
-    //             let w_copy = String::from(word);
-    //             let mut location = locations.iter_mut().as_slice();
-    //             location[0].word = word;
-    //             self.placed_words.insert(w_copy, location[0]);
-    //         } else {
-    //             self.unplaced_words.push(String::from(word));
-    //         }
-    //         i += 1;
-    //     }
-    // }
+    // Re-rolls filler letters until every word can only be read where it was
+    // placed and no forbidden word can be read in any orientation, words
+    // that cannot be cleaned up this way are added as warnings
+    fn clean_filler(&mut self, puzzle: &mut Grid, placements: &[(usize, Location)]) {
+        const MAX_REROLLS: i32 = 100;
+        let placed_cells: HashSet<Position> = placements
+            .iter()
+            .flat_map(|(_, location)| location.cells())
+            .collect();
+        let own_cells: Vec<Vec<Position>> = placements
+            .iter()
+            .map(|(_, location)| {
+                let mut cells = location.cells();
+                cells.sort();
+                cells
+            })
+            .collect();
+        let mut forbidden: Vec<String> = Vec::new();
+        for word in self.settings.forbidden_words.iter() {
+            let text = self.settings.normalization.apply(word).to_lowercase();
+            if !text.is_empty() && !forbidden.contains(&text) {
+                forbidden.push(text);
+            }
+        }
+        // The letters of a hidden message cannot be changed
+        let locked = !self.message.is_empty();
+        let filler = self.filler();
+        let mut rerolls = 0;
+
+        loop {
+            // Occurrences made only of placed letters cannot be fixed
+            let mut reroll: Vec<Position> = Vec::new();
+            let mut add_filler_cells = |cells: Vec<Position>| {
+                for cell in cells {
+                    if !locked && !placed_cells.contains(&cell) && !reroll.contains(&cell) {
+                        reroll.push(cell);
+                    }
+                }
+            };
+            if self.settings.unique_words {
+                for word in self.words.iter() {
+                    let occurrences = find_occurrences(
+                        puzzle,
+                        &word.text,
+                        &self.settings.orientations,
+                        self.settings.wrap_around,
+                    );
+                    for occurrence in occurrences.iter() {
+                        let mut cells = occurrence.cells();
+                        cells.sort();
+                        if !own_cells.contains(&cells) {
+                            add_filler_cells(cells);
+                        }
+                    }
+                }
+            }
+            let lowercase = lowercase_grid(puzzle);
+            for word in forbidden.iter() {
+                for occurrence in find_occurrences(
+                    &lowercase,
+                    word,
+                    &ALL_ORIENTATIONS,
+                    self.settings.wrap_around,
+                ) {
+                    add_filler_cells(occurrence.cells());
+                }
+            }
+
+            if reroll.is_empty() || rerolls == MAX_REROLLS {
+                break;
+            }
+            for cell in reroll {
+                puzzle[cell.y as usize][cell.x as usize] = filler.rand_letter(self.rng.as_mut());
+            }
+            rerolls += 1;
+        }
+
+        if self.settings.unique_words {
+            for word in self.words.iter() {
+                let count = find_occurrences(
+                    puzzle,
+                    &word.text,
+                    &self.settings.orientations,
+                    self.settings.wrap_around,
+                )
+                .len();
+                if count > 1 {
+                    self.puzzle.add_warning(&format!(
+                        "Word {:?} appears {} times in the puzzle",
+                        word.display, count
+                    ));
+                }
+            }
+        }
+        let lowercase = lowercase_grid(puzzle);
+        for word in forbidden.iter() {
+            if !find_occurrences(
+                &lowercase,
+                word,
+                &ALL_ORIENTATIONS,
+                self.settings.wrap_around,
+            )
+            .is_empty()
+            {
+                self.puzzle
+                    .add_warning(&format!("Forbidden word {:?} appears in the puzzle", word));
+            }
+        }
+    }
+
+    fn finish_statistics(&mut self, started: f64) {
+        self.statistics.width = self.settings.width;
+        self.statistics.height = self.settings.height;
+        self.statistics.elapsed_ms = now_ms() - started;
+    }
+
+    // Builds the answer key from the locations chosen while filling the
+    // puzzle, the `skipped` words left out of it are not placed
+    fn record_placements(&mut self, placements: Placements, skipped: &[usize]) {
+        self.placed_words = HashMap::new();
+        self.unplaced_words = Vec::new();
+        for (i, location) in placements.into_iter() {
+            let display = self.words[i].display.clone();
+            self.placed_words.insert(display, location);
+        }
+        let mut skipped = skipped.to_vec();
+        skipped.sort_unstable();
+        for i in skipped {
+            self.unplaced_words.push(self.words[i].display.clone());
+        }
+    }
+
+    // Finds the first location of the word in the puzzle, reading it
+    // in every orientation, or `None` if the word cannot be found
+    pub fn find_word(&self, word: &str, puzzle: &[Vec<String>]) -> Option<Location> {
+        find_occurrences(puzzle, word, &ALL_ORIENTATIONS, self.settings.wrap_around)
+            .into_iter()
+            .next()
+    }
+
+    // Solves the finished puzzle by scanning the grid, recording where each
+    // word starts in `placed_words` and every word that could not be found
+    // in `unplaced_words`
+    //
+    // **Note:** `create` already fills the answer key from the generator,
+    // use this to re-check a puzzle loaded from elsewhere
+    pub fn solve(&mut self) {
+        self.placed_words = HashMap::new();
+        self.unplaced_words = Vec::new();
+        let mut i = 0;
+        while i < self.words.len() {
+            let word = &self.words[i];
+            match self.find_word(&word.text, &self.puzzle.puzzle) {
+                Some(location) => {
+                    self.placed_words.insert(word.display.clone(), location);
+                }
+                None => self.unplaced_words.push(word.display.clone()),
+            }
+            i += 1;
+        }
+    }
+
+    // The words as given in the word list, use this for the word bank
+    //
+    // **Note:** Words left out of the puzzle are not included
+    pub fn word_bank(&self) -> Vec<String> {
+        self.words
+            .iter()
+            .map(|word| word.display.clone())
+            .filter(|display| {
+                !self.puzzle.words_not_placed.contains(display)
+                    && !self.puzzle.words_not_used.contains(display)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| String::from(*word)).collect()
+    }
+
+    fn grid(rows: &[&str]) -> Grid {
+        rows.iter()
+            .map(|row| letters(row).into_iter().map(String::from).collect())
+            .collect()
+    }
+
     #[test]
     fn test_word_search() {
         let words: Vec<String> = vec![
@@ -277,9 +1102,994 @@ mod tests {
             String::from("test"),
             String::from("mark"),
         ];
-        let word_search = WordSearch::new(&words, 4, 4);
+        let word_search = WordSearch::new(&words, 4, 4).unwrap();
         assert_eq!(word_search.words.len(), 4);
         assert_eq!(word_search.settings.width, 5);
         assert_eq!(word_search.settings.height, 5);
     }
+
+    #[test]
+    fn test_seeded_create() {
+        let words = words(&["hello", "world", "dart", "rust"]);
+        let settings = || Settings {
+            width: 6,
+            height: 6,
+            seed: Some(1234),
+            ..Settings::default()
+        };
+        let mut a = WordSearch::with_settings(&words, settings()).unwrap();
+        let mut b = WordSearch::with_settings(&words, settings()).unwrap();
+        let grid_a = a.create().unwrap().puzzle.puzzle.clone();
+        let grid_b = b.create().unwrap().puzzle.puzzle.clone();
+        assert_eq!(a.seed(), 1234);
+        assert_eq!(grid_a, grid_b);
+        assert_eq!(grid_a, a.create().unwrap().puzzle.puzzle);
+
+        assert_eq!(a.placed_words.len(), words.len());
+        for location in a.placed_words.values() {
+            let next_orientation = get_orientation(&location.orientation);
+            for (i, letter) in letters(&location.word).into_iter().enumerate() {
+                let position = next_orientation(location.x, location.y, i as i32);
+                assert_eq!(grid_a[position.y as usize][position.x as usize], letter);
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_orientations() {
+        let words = words(&[
+            "north", "south", "east", "west", "up", "down", "left", "right",
+        ]);
+        let mut orientations_used: Vec<Orientation> = Vec::new();
+        let mut seed = 0;
+        while seed < 10 {
+            let settings = Settings {
+                width: 8,
+                height: 8,
+                orientations: ALL_ORIENTATIONS.to_vec(),
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            for location in output.placed_words.values() {
+                assert!(output
+                    .find_word(&location.word, &output.puzzle.puzzle)
+                    .is_some());
+                if !orientations_used.contains(&location.orientation) {
+                    orientations_used.push(location.orientation);
+                }
+            }
+            seed += 1;
+        }
+        assert_eq!(orientations_used.len(), ALL_ORIENTATIONS.len());
+    }
+
+    fn shared_cells(word_search: &WordSearch) -> i32 {
+        word_search
+            .placed_words
+            .values()
+            .map(|location| location.overlap)
+            .sum()
+    }
+
+    #[test]
+    fn test_overlap_policy() {
+        let words = words(&["rust", "trust", "stun", "sun", "nut", "tusk"]);
+        let mut seed = 0;
+        while seed < 10 {
+            let create = |overlap: OverlapPolicy| {
+                let settings = Settings {
+                    width: 6,
+                    height: 6,
+                    overlap,
+                    seed: Some(seed),
+                    ..Settings::default()
+                };
+                let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+                word_search.create().unwrap();
+                word_search
+            };
+            let disallowed = create(OverlapPolicy::Disallow);
+            assert_eq!(shared_cells(&disallowed), 0);
+            let preferred = create(OverlapPolicy::Prefer);
+            let avoided = create(OverlapPolicy::Avoid);
+            assert!(shared_cells(&preferred) >= shared_cells(&avoided));
+            seed += 1;
+        }
+    }
+
+    #[test]
+    fn test_filler_alphabet() {
+        let words = words(&["HELLO", "WORLD"]);
+        let settings = Settings {
+            width: 8,
+            height: 8,
+            seed: Some(3),
+            filler_alphabet: String::from("XYZ"),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        let mut word_cells = 0;
+        for row in output.puzzle.puzzle.iter() {
+            for c in row.iter() {
+                assert!("HELOWRDXYZ".contains(c.as_str()));
+                if "HELOWRD".contains(c.as_str()) {
+                    word_cells += 1;
+                }
+            }
+        }
+        let extra_letters = 64 - word_cells;
+        assert_eq!(
+            output.grid_fill_percentage,
+            (1.0 - extra_letters as f64 / 64.0) * 100.0
+        );
+    }
+
+    #[test]
+    fn test_unicode_words() {
+        let words = words(&["café", "straße", "한국어", "नमस्ते", "👍🏽👍"]);
+        let settings = Settings {
+            width: 4,
+            height: 4,
+            seed: Some(9),
+            filler_alphabet: String::from("가나다"),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        // "straße" is the longest word with 6 letters, not 7 bytes
+        assert_eq!(word_search.max_word_len, 6);
+        let output = word_search.create().unwrap();
+        for word in words.iter() {
+            let location = &output.placed_words[word];
+            let next_orientation = get_orientation(&location.orientation);
+            for (i, letter) in letters(word).into_iter().enumerate() {
+                let position = next_orientation(location.x, location.y, i as i32);
+                assert_eq!(
+                    output.puzzle.puzzle[position.y as usize][position.x as usize],
+                    letter
+                );
+            }
+            assert!(output.find_word(word, &output.puzzle.puzzle).is_some());
+        }
+    }
+
+    #[test]
+    fn test_normalized_words() {
+        let words = words(&["Ice Cream", "rock'n'roll", "Crème Brûlée"]);
+        let settings = Settings {
+            seed: Some(4),
+            normalization: Normalization {
+                case: CaseFolding::Lower,
+                accents: Accents::Strip,
+                ..Normalization::default()
+            },
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        assert_eq!(word_search.word_bank(), words);
+        // "cremebrulee" has 11 letters
+        assert_eq!(word_search.max_word_len, 11);
+
+        let output = word_search.create().unwrap();
+        assert_eq!(output.placed_words["Ice Cream"].word, "icecream");
+        assert_eq!(output.placed_words["rock'n'roll"].word, "rocknroll");
+        assert_eq!(output.placed_words["Crème Brûlée"].word, "cremebrulee");
+
+        output.solve();
+        assert_eq!(output.placed_words.len(), words.len());
+        assert!(output.unplaced_words.is_empty());
+    }
+
+    #[test]
+    fn test_same_letter_words() {
+        // Words with the same letters each get their own answer
+        let words = words(&["ice cream", "icecream"]);
+        let settings = Settings {
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert!(output.placed_words.contains_key("ice cream"));
+        assert!(output.placed_words.contains_key("icecream"));
+    }
+
+    #[test]
+    fn test_duplicate_words() {
+        let given = words(&["start", "art", "moon", "moon", "noom"]);
+        let create = |duplicates: DuplicatePolicy| {
+            let settings = Settings {
+                seed: Some(2),
+                duplicates,
+                ..Settings::default()
+            };
+            WordSearch::with_settings(&given, settings)
+        };
+
+        let mut warned = create(DuplicatePolicy::Warn).unwrap();
+        assert_eq!(warned.word_bank(), vec!["start", "art", "moon", "noom"]);
+        let output = warned.create().unwrap();
+        assert_eq!(
+            output.puzzle.warnings,
+            vec![
+                String::from("Word \"art\" can be found inside \"start\""),
+                String::from("Word \"moon\" is a duplicate of \"moon\", \"moon\" was left out"),
+                String::from("Word \"noom\" can be found backwards inside \"moon\""),
+                String::from("Word \"art\" appears 2 times in the puzzle"),
+            ]
+        );
+        assert_eq!(output.placed_words.len(), 4);
+
+        // Kept words are never written entirely over the word they are in
+        let mut seed = 0;
+        while seed < 5 {
+            let settings = Settings {
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(
+                &words(&["moon", "moon", "sun", "start", "art"]),
+                settings,
+            )
+            .unwrap();
+            let output = word_search.create().unwrap();
+            assert_eq!(output.placed_words.len(), output.word_bank().len());
+            for location in output.placed_words.values() {
+                assert!(location.overlap < letters(&location.word).len() as i32);
+            }
+            seed += 1;
+        }
+
+        let mut dropped = create(DuplicatePolicy::Drop).unwrap();
+        assert_eq!(dropped.word_bank(), vec!["start", "moon"]);
+        let output = dropped.create().unwrap();
+        assert_eq!(
+            output.puzzle.warnings,
+            vec![
+                String::from("Word \"art\" can be found inside \"start\", \"art\" was left out"),
+                String::from("Word \"moon\" is a duplicate of \"moon\", \"moon\" was left out"),
+                String::from(
+                    "Word \"noom\" can be found backwards inside \"moon\", \"noom\" was left out"
+                ),
+            ]
+        );
+
+        assert_eq!(
+            create(DuplicatePolicy::Fail).err(),
+            Some(WordSearchError::DuplicateWord {
+                word: String::from("art"),
+                other: String::from("start")
+            })
+        );
+    }
+
+    #[test]
+    fn test_unique_words() {
+        // A tiny alphabet makes the filler spell the words by chance
+        let words = words(&["abc", "cab"]);
+        let mut seed = 0;
+        while seed < 20 {
+            let settings = Settings {
+                width: 5,
+                height: 5,
+                orientations: ALL_ORIENTATIONS.to_vec(),
+                seed: Some(seed),
+                filler_alphabet: String::from("abc"),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            let grid = output.puzzle.puzzle.clone();
+            for word in words.iter() {
+                assert_eq!(
+                    find_occurrences(&grid, word, &ALL_ORIENTATIONS, false).len(),
+                    1,
+                    "seed {}",
+                    seed
+                );
+            }
+            seed += 1;
+        }
+    }
+
+    #[test]
+    fn test_forbidden_words() {
+        let words = words(&["xyz"]);
+        let mut seed = 0;
+        while seed < 20 {
+            let settings = Settings {
+                width: 5,
+                height: 5,
+                seed: Some(seed),
+                filler_alphabet: String::from("ABC"),
+                unique_words: false,
+                forbidden_words: vec![String::from("Cab!")],
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            let grid = lowercase_grid(&output.puzzle.puzzle);
+            assert!(
+                find_occurrences(&grid, "cab", &ALL_ORIENTATIONS, false).is_empty(),
+                "seed {}",
+                seed
+            );
+            assert!(output.puzzle.warnings.is_empty());
+            seed += 1;
+        }
+
+        // Forbidden words spelled by the placed words are only reported
+        let settings = Settings {
+            width: 5,
+            height: 5,
+            seed: Some(1),
+            forbidden_words: vec![String::from("cab")],
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&[String::from("cab")], settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!(
+            output.puzzle.warnings,
+            vec![String::from("Forbidden word \"cab\" appears in the puzzle")]
+        );
+    }
+
+    #[test]
+    fn test_backtracking() {
+        // The words only fit as rows crossed by columns
+        let words = words(&["abcd", "efgh", "mnop", "aeim", "bfjn", "cgko", "dhlp"]);
+        let create = |seed: u64, max_backtracks: i32| {
+            let settings = Settings {
+                width: 4,
+                height: 4,
+                orientations: vec![Orientation::Horizontal, Orientation::Vertical],
+                max_attempts: 1,
+                max_backtracks,
+                max_grid_growth: 0,
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            word_search.create().map(|output| output.statistics.clone())
+        };
+        let mut failures = 0;
+        let mut seed = 0;
+        while seed < 20 {
+            if create(seed, 0).is_err() {
+                failures += 1;
+            }
+            let statistics = create(seed, 1000).unwrap();
+            assert_eq!((statistics.width, statistics.height), (4, 4));
+            seed += 1;
+        }
+        assert!(failures > 0);
+    }
+
+    #[test]
+    fn test_backtrack_budget() {
+        // The attempts at a grid size share one budget
+        let settings = Settings {
+            width: 4,
+            height: 4,
+            orientations: vec![Orientation::Horizontal, Orientation::Vertical],
+            max_attempts: 5,
+            max_backtracks: 10,
+            max_grid_growth: 0,
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let words = words(&["abcd", "efgh", "ijkl", "mnop", "qrst", "uvwx"]);
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        assert!(word_search.create().is_err());
+        assert!(word_search.statistics.backtracks <= 10);
+    }
+
+    #[test]
+    fn test_invalid_words() {
+        assert_eq!(
+            WordSearch::new(&[], 5, 5).err(),
+            Some(WordSearchError::EmptyWordList)
+        );
+        assert_eq!(
+            WordSearch::new(&[String::from("")], 5, 5).err(),
+            Some(WordSearchError::EmptyWord)
+        );
+        assert_eq!(
+            WordSearch::new(&[String::from("?!")], 5, 5).err(),
+            Some(WordSearchError::EmptyWord)
+        );
+        let settings = Settings {
+            normalization: Normalization::none(),
+            ..Settings::default()
+        };
+        assert_eq!(
+            WordSearch::with_settings(&[String::from("ice cream")], settings).err(),
+            Some(WordSearchError::InvalidCharacters {
+                word: String::from("ice cream")
+            })
+        );
+        // The grid widens to fit long words without using up its growth
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            max_grid_growth: 0,
+            ..Settings::default()
+        };
+        let word_search = WordSearch::with_settings(&[String::from("hello")], settings).unwrap();
+        assert_eq!(word_search.settings.width, 5);
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            max_width: Some(4),
+            max_height: Some(4),
+            ..Settings::default()
+        };
+        assert_eq!(
+            WordSearch::with_settings(&[String::from("hello")], settings).err(),
+            Some(WordSearchError::WordTooLong {
+                word: String::from("hello"),
+                length: 5,
+                max: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_grid_growth_exhausted() {
+        let words = words(&["ab", "cd", "ef"]);
+        let settings = Settings {
+            width: 2,
+            height: 2,
+            orientations: vec![Orientation::Horizontal],
+            max_grid_growth: 0,
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        assert_eq!(
+            word_search.create().err(),
+            Some(WordSearchError::GridGrowthExhausted {
+                width: 2,
+                height: 2
+            })
+        );
+        assert_eq!(word_search.statistics.total_attempts(), 10);
+        assert_eq!(word_search.statistics.grid_growths, 0);
+    }
+
+    #[test]
+    fn test_fixed_size() {
+        let words = words(&["abc", "def", "ghi", "jkl"]);
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            fixed_size: true,
+            orientations: vec![Orientation::Horizontal],
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!((output.statistics.width, output.statistics.height), (3, 3));
+        assert_eq!(output.statistics.grid_growths, 0);
+        assert_eq!(output.placed_words.len(), 3);
+        assert_eq!(output.puzzle.words_not_placed.len(), 1);
+        assert!(!output
+            .placed_words
+            .contains_key(&output.puzzle.words_not_placed[0]));
+        assert_eq!(
+            output.puzzle.warnings,
+            vec![String::from("Only 3 of 4 words fit in the puzzle")]
+        );
+        assert_eq!(output.word_bank().len(), 3);
+
+        // Words longer than the fixed grid are still an error
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            fixed_size: true,
+            ..Settings::default()
+        };
+        assert_eq!(
+            WordSearch::with_settings(&[String::from("abcd")], settings).err(),
+            Some(WordSearchError::WordTooLong {
+                word: String::from("abcd"),
+                length: 4,
+                max: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_best_effort() {
+        let words = words(&["ab", "cd", "ef"]);
+        let settings = Settings {
+            width: 2,
+            height: 2,
+            orientations: vec![Orientation::Horizontal],
+            max_grid_growth: 1,
+            best_effort: true,
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!((output.statistics.width, output.statistics.height), (3, 3));
+        assert_eq!(output.placed_words.len(), 3);
+        assert!(output.puzzle.warnings.is_empty());
+
+        let settings = Settings {
+            width: 2,
+            height: 2,
+            orientations: vec![Orientation::Horizontal],
+            max_grid_growth: 0,
+            best_effort: true,
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!((output.statistics.width, output.statistics.height), (2, 2));
+        assert_eq!(output.placed_words.len(), 2);
+        assert_eq!(output.puzzle.words_not_placed.len(), 1);
+        assert_eq!(
+            output.puzzle.warnings,
+            vec![String::from("Only 2 of 3 words fit in the puzzle")]
+        );
+        // The answer key agrees with the solver on the words left out
+        assert_eq!(output.unplaced_words, output.puzzle.words_not_placed);
+        let placed = output.placed_words.clone();
+        output.solve();
+        assert_eq!(output.placed_words, placed);
+        assert_eq!(output.unplaced_words, output.puzzle.words_not_placed);
+    }
+
+    #[test]
+    fn test_target_density() {
+        let words = words(&["abc", "def"]);
+        let settings = Settings {
+            target_density: Some(0.5),
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!((output.statistics.width, output.statistics.height), (3, 4));
+        assert!(output.puzzle.words_not_used.is_empty());
+    }
+
+    #[test]
+    fn test_target_density_pool() {
+        // Words are picked from the pool when there are too many
+        let words = words(&["abcd", "efgh", "ijkl", "mnop", "qrst", "uvwx"]);
+        let settings = Settings {
+            width: 4,
+            height: 4,
+            fixed_size: true,
+            target_density: Some(0.5),
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!(output.placed_words.len(), 2);
+        assert_eq!(output.puzzle.words_not_used.len(), 4);
+        assert!(output.puzzle.words_not_placed.is_empty());
+        assert_eq!(output.word_bank().len(), 2);
+        assert_eq!(output.grid_fill_percentage, 50.0);
+    }
+
+    #[test]
+    fn test_mask() {
+        // A diamond, the corners cannot hold letters
+        let mask = Mask::from_fn(5, 5, |x, y| (x - 2).abs() + (y - 2).abs() <= 2);
+        let words = words(&["abcde", "fg"]);
+        let mut seed = 0;
+        while seed < 10 {
+            let settings = Settings {
+                width: 20,
+                mask: Some(mask.clone()),
+                orientations: ALL_ORIENTATIONS.to_vec(),
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            assert_eq!((output.statistics.width, output.statistics.height), (5, 5));
+            let mut y = 0;
+            while y < 5 {
+                let mut x = 0;
+                while x < 5 {
+                    let cell = &output.puzzle.puzzle[y as usize][x as usize];
+                    assert_eq!(cell.is_empty(), !mask.is_usable(x, y), "seed {}", seed);
+                    x += 1;
+                }
+                y += 1;
+            }
+            for location in output.placed_words.values() {
+                for cell in location.cells() {
+                    assert!(mask.is_usable(cell.x, cell.y));
+                }
+            }
+            seed += 1;
+        }
+
+        // Shaped grids never grow
+        let settings = Settings {
+            mask: Some(Mask::from_fn(3, 3, |x, _| x == 0)),
+            orientations: vec![Orientation::Horizontal],
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&[String::from("abc")], settings).unwrap();
+        assert_eq!(
+            word_search.create().err(),
+            Some(WordSearchError::GridGrowthExhausted {
+                width: 3,
+                height: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_hidden_message() {
+        let words = words(&["abc", "cde"]);
+        let mut seed = 0;
+        while seed < 10 {
+            let settings = Settings {
+                width: 3,
+                height: 3,
+                hidden_message: Some(String::from("w-x y z")),
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            assert_eq!((output.statistics.width, output.statistics.height), (3, 3));
+            let placed: HashSet<Position> = output
+                .placed_words
+                .values()
+                .flat_map(|location| location.cells())
+                .collect();
+            let mut message = String::new();
+            let mut y = 0;
+            while y < 3 {
+                let mut x = 0;
+                while x < 3 {
+                    if !placed.contains(&Position::from(x, y)) {
+                        message.push_str(&output.puzzle.puzzle[y as usize][x as usize]);
+                    }
+                    x += 1;
+                }
+                y += 1;
+            }
+            assert_eq!(message, "wxyz", "seed {}", seed);
+            assert!(output.puzzle.warnings.is_empty());
+            seed += 1;
+        }
+
+        // Words that could share letters keep apart for a longer message
+        let mut seed = 0;
+        while seed < 10 {
+            let settings = Settings {
+                width: 3,
+                height: 3,
+                hidden_message: Some(String::from("xyz")),
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            assert_eq!((output.statistics.width, output.statistics.height), (3, 3));
+            assert_eq!(output.grid_fill_percentage, (1.0 - 3.0 / 9.0) * 100.0);
+            seed += 1;
+        }
+
+        // A bigger grid would only leave more cells blank
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            overlap: OverlapPolicy::Disallow,
+            hidden_message: Some(String::from("wxyz")),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        assert_eq!(
+            word_search.create().err(),
+            Some(WordSearchError::MessageBlanksNotReached {
+                length: 4,
+                width: 3,
+                height: 3
+            })
+        );
+        assert_eq!(word_search.statistics.grid_growths, 0);
+
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            hidden_message: Some(String::from("hi")),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        assert_eq!(
+            word_search.create().err(),
+            Some(WordSearchError::MessageTooShort {
+                length: 2,
+                blanks: 3
+            })
+        );
+
+        let settings = Settings {
+            hidden_message: Some(String::from("?!")),
+            ..Settings::default()
+        };
+        assert_eq!(
+            WordSearch::with_settings(&words, settings)
+                .err()
+                .map(|error| error.code()),
+            Some("INVALID_SETTINGS")
+        );
+    }
+
+    #[test]
+    fn test_pinned_words() {
+        let words = words(&["cat", "tea"]);
+        let mut seed = 0;
+        while seed < 10 {
+            let settings = Settings {
+                width: 5,
+                height: 5,
+                pinned_words: vec![
+                    PinnedWord::new("Title", 0, 0, Orientation::Horizontal),
+                    PinnedWord::new("tacos", 2, 0, Orientation::Vertical),
+                ],
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            assert_eq!(output.placed_words.len(), 4);
+            let title = &output.placed_words["Title"];
+            assert_eq!((title.x, title.y), (0, 0));
+            assert_eq!(title.orientation, Orientation::Horizontal);
+            let tacos = &output.placed_words["tacos"];
+            assert_eq!((tacos.x, tacos.y, tacos.overlap), (2, 0, 1));
+            assert_eq!(output.puzzle.puzzle[0].concat(), "Title");
+            assert_eq!(output.word_bank()[0], "Title");
+            seed += 1;
+        }
+
+        let settings = Settings {
+            pinned_words: vec![
+                PinnedWord::new("abc", 0, 0, Orientation::Horizontal),
+                PinnedWord::new("xyz", 1, 0, Orientation::Vertical),
+            ],
+            ..Settings::default()
+        };
+        assert_eq!(
+            WordSearch::with_settings(&words, settings).err(),
+            Some(WordSearchError::PinnedWordConflict {
+                word: String::from("xyz"),
+                other: String::from("abc")
+            })
+        );
+
+        // The grid widens for pinned words like any other word
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            pinned_words: vec![PinnedWord::new("Title", 0, 0, Orientation::Horizontal)],
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!(output.puzzle.puzzle[0].concat(), "Title");
+
+        // Blanks left around pinned words alone must still fit the message
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            pinned_words: vec![PinnedWord::new("abc", 0, 0, Orientation::Horizontal)],
+            hidden_message: Some(String::from("defghijk")),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&[], settings).unwrap();
+        assert_eq!(
+            word_search.create().err(),
+            Some(WordSearchError::MessageBlanksNotReached {
+                length: 8,
+                width: 3,
+                height: 3
+            })
+        );
+
+        let settings = Settings {
+            pinned_words: vec![PinnedWord::new("abc", 1, 0, Orientation::HorizontalBack)],
+            ..Settings::default()
+        };
+        assert_eq!(
+            WordSearch::with_settings(&words, settings)
+                .err()
+                .map(|error| error.code()),
+            Some("INVALID_SETTINGS")
+        );
+    }
+
+    #[test]
+    fn test_wrap_around() {
+        let words = words(&["abcd", "efgh", "ijkl"]);
+        let mut wrapped = 0;
+        let mut seed = 0;
+        while seed < 10 {
+            let settings = Settings {
+                width: 4,
+                height: 4,
+                wrap_around: true,
+                orientations: ALL_ORIENTATIONS.to_vec(),
+                seed: Some(seed),
+                ..Settings::default()
+            };
+            let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+            let output = word_search.create().unwrap();
+            let grid = output.puzzle.puzzle.clone();
+            for (word, location) in output.placed_words.iter() {
+                let spelled: String = location
+                    .cells()
+                    .iter()
+                    .map(|cell| grid[cell.y as usize][cell.x as usize].clone())
+                    .collect();
+                assert_eq!(&spelled, word, "seed {}", seed);
+                // Words that would not fit without wrapping
+                if !check_orientation(&location.orientation)(location.x, location.y, 4, 4, 4) {
+                    wrapped += 1;
+                }
+            }
+
+            // The solver reads the words across the edges as well
+            let placed = output.placed_words.clone();
+            output.solve();
+            assert!(output.unplaced_words.is_empty());
+            for (word, location) in output.placed_words.iter() {
+                let mut found = location.cells();
+                let mut expected = placed[word].cells();
+                found.sort();
+                expected.sort();
+                assert_eq!(found, expected, "seed {}", seed);
+            }
+            seed += 1;
+        }
+        assert!(wrapped > 0);
+    }
+
+    #[test]
+    fn test_max_width() {
+        let words = words(&["abc", "def", "ghi", "jkl"]);
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            max_width: Some(3),
+            orientations: vec![Orientation::Horizontal],
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!((output.statistics.width, output.statistics.height), (3, 4));
+
+        let settings = Settings {
+            width: 3,
+            height: 3,
+            max_width: Some(3),
+            max_height: Some(3),
+            orientations: vec![Orientation::Horizontal],
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        assert_eq!(
+            word_search.create().err(),
+            Some(WordSearchError::GridGrowthExhausted {
+                width: 3,
+                height: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_attempt_statistics() {
+        let words = words(&["ab", "cd", "ef"]);
+        let settings = Settings {
+            width: 2,
+            height: 2,
+            orientations: vec![Orientation::Horizontal],
+            max_attempts: 3,
+            max_grid_growth: 1,
+            seed: Some(1),
+            ..Settings::default()
+        };
+        let mut word_search = WordSearch::with_settings(&words, settings).unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!(
+            output.statistics.attempts,
+            vec![
+                SizeAttempts {
+                    width: 2,
+                    height: 2,
+                    attempts: 3
+                },
+                SizeAttempts {
+                    width: 3,
+                    height: 3,
+                    attempts: 1
+                },
+            ]
+        );
+        assert_eq!(output.statistics.grid_growths, 1);
+        assert_eq!((output.statistics.width, output.statistics.height), (3, 3));
+        assert!(output.statistics.elapsed_ms >= 0.0);
+    }
+
+    struct Counter(u64);
+
+    impl RandomSource for Counter {
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_custom_random_source() {
+        let words = words(&["native", "rust"]);
+        let mut word_search =
+            WordSearch::with_random_source(&words, Settings::default(), Box::new(Counter(0)))
+                .unwrap();
+        let output = word_search.create().unwrap();
+        assert_eq!(output.placed_words.len(), words.len());
+        assert!(output.puzzle.puzzle.iter().all(|row| row
+            .iter()
+            .all(|c| c.len() == 1 && c.chars().all(|c| c.is_ascii_lowercase()))));
+    }
+
+    #[test]
+    fn test_solve() {
+        let words = words(&["cat", "dog", "owl", "ant", "tac", "god", "bee"]);
+        let mut word_search = WordSearch::new(&words, 4, 4).unwrap();
+        word_search.puzzle.puzzle = grid(&["catl", "dxwx", "ooxx", "gant"]);
+        word_search.solve();
+
+        let cat = &word_search.placed_words["cat"];
+        assert_eq!(
+            (cat.x, cat.y, cat.orientation),
+            (0, 0, Orientation::Horizontal)
+        );
+        let dog = &word_search.placed_words["dog"];
+        assert_eq!(
+            (dog.x, dog.y, dog.orientation),
+            (0, 1, Orientation::Vertical)
+        );
+        let owl = &word_search.placed_words["owl"];
+        assert_eq!(
+            (owl.x, owl.y, owl.orientation),
+            (1, 2, Orientation::DiagonalUp)
+        );
+        let ant = &word_search.placed_words["ant"];
+        assert_eq!(
+            (ant.x, ant.y, ant.orientation),
+            (1, 3, Orientation::Horizontal)
+        );
+        let tac = &word_search.placed_words["tac"];
+        assert_eq!(
+            (tac.x, tac.y, tac.orientation),
+            (2, 0, Orientation::HorizontalBack)
+        );
+        let god = &word_search.placed_words["god"];
+        assert_eq!(
+            (god.x, god.y, god.orientation),
+            (0, 3, Orientation::VerticalUp)
+        );
+        assert_eq!(word_search.unplaced_words, vec![String::from("bee")]);
+    }
 }
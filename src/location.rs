@@ -1,7 +1,9 @@
 use crate::orientations::*;
+use crate::position::Position;
+use crate::utils::letters;
 
-#[derive(Debug, Clone, Copy)]
-pub struct Location<'a> {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
     // The col where the word starts
     pub x: i32,
     // The row where the word starts
@@ -9,22 +11,80 @@ pub struct Location<'a> {
     // The number of overlaps the word has
     pub overlap: i32,
     // The orientation of the word
-    pub orientation: &'a Orientation,
+    pub orientation: Orientation,
     // The word itself
-    pub word: &'a str,
+    pub word: String,
+    // Width and height of the grid the word wraps around, `None` when
+    // the word stops at the edges of the grid
+    pub wrap: Option<(i32, i32)>,
 }
 
-impl<'a> Location<'a> {
+impl Location {
     // Constructor
-    pub fn from(x: i32, y: i32, overlap: i32, orientation: &'a Orientation, word: &'a str) -> Location<'a> {
+    pub fn from(x: i32, y: i32, overlap: i32, orientation: Orientation, word: &str) -> Location {
         Location {
             x,
             y,
             overlap,
             orientation,
-            word,
+            word: String::from(word),
+            wrap: None,
         }
     }
+
+    // Lets the word run off one edge of a `width` x `height` grid
+    // and continue from the opposite edge
+    pub fn wrap_around(mut self, width: i32, height: i32) -> Location {
+        self.wrap = Some((width, height));
+        self
+    }
+
+    // The cells taken by the word, in reading order
+    pub fn cells(&self) -> Vec<Position> {
+        word_cells(
+            self.x,
+            self.y,
+            letters(&self.word).len() as i32,
+            &self.orientation,
+            self.wrap,
+        )
+    }
+
+    // Whether the word takes any cell more than once, which
+    // can only happen when it wraps around a small grid
+    pub fn overlaps_itself(&self) -> bool {
+        self.wrap.is_some() && repeats_cells(&self.cells())
+    }
+}
+
+// The cells taken by a word of `len` letters starting at `x`, `y`, wrapping
+// around a grid of the `wrap` width and height when set
+pub fn word_cells(
+    x: i32,
+    y: i32,
+    len: i32,
+    orientation: &Orientation,
+    wrap: Option<(i32, i32)>,
+) -> Vec<Position> {
+    let next_orientation = get_orientation(orientation);
+    (0..len)
+        .map(|i| {
+            let position = next_orientation(x, y, i);
+            match wrap {
+                Some((width, height)) => {
+                    Position::from(position.x.rem_euclid(width), position.y.rem_euclid(height))
+                }
+                None => position,
+            }
+        })
+        .collect()
+}
+
+// Whether any cell is in the list more than once
+pub fn repeats_cells(cells: &[Position]) -> bool {
+    let mut cells = cells.to_vec();
+    cells.sort();
+    cells.windows(2).any(|pair| pair[0] == pair[1])
 }
 
 #[cfg(test)]
@@ -37,10 +97,44 @@ mod tests {
         let overlap = 3;
         let orientation = Orientation::Horizontal;
         let word = String::from("test");
-        let location = Location::from(x, y, overlap, &orientation, &word);
+        let location = Location::from(x, y, overlap, orientation, &word);
         assert_eq!(location.x, x);
         assert_eq!(location.y, y);
         assert_eq!(location.overlap, overlap);
+        assert_eq!(location.orientation, orientation);
         assert_eq!(location.word, word);
     }
+
+    #[test]
+    fn test_cells() {
+        let location = Location::from(3, 1, 0, Orientation::DiagonalUpBack, "café");
+        assert_eq!(
+            location.cells(),
+            vec![
+                Position::from(3, 1),
+                Position::from(2, 2),
+                Position::from(1, 3),
+                Position::from(0, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrapped_cells() {
+        let location = Location::from(3, 0, 0, Orientation::Horizontal, "abc").wrap_around(4, 4);
+        assert_eq!(
+            location.cells(),
+            vec![
+                Position::from(3, 0),
+                Position::from(0, 0),
+                Position::from(1, 0),
+            ]
+        );
+        assert!(!location.overlaps_itself());
+
+        let location = Location::from(0, 1, 0, Orientation::VerticalUp, "abc").wrap_around(2, 2);
+        assert_eq!(location.cells()[1], Position::from(0, 0));
+        assert_eq!(location.cells()[2], Position::from(0, 1));
+        assert!(location.overlaps_itself());
+    }
 }
@@ -0,0 +1,423 @@
+use crate::error::WordSearchError;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+// Shape of the grid, only the usable cells hold letters so puzzles can be
+// hearts, stars, circles or any other outline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mask {
+    // Rows of cells, `true` where a letter can go
+    cells: Vec<Vec<bool>>,
+}
+
+impl Mask {
+    pub fn new(cells: Vec<Vec<bool>>) -> Mask {
+        Mask { cells }
+    }
+
+    // A rectangle where every cell is usable
+    pub fn full(width: i32, height: i32) -> Mask {
+        Mask::from_fn(width, height, |_, _| true)
+    }
+
+    // Builds a mask by asking `usable` about every cell
+    //
+    // ```
+    // let circle = Mask::from_fn(9, 9, |x, y| (x - 4).pow(2) + (y - 4).pow(2) <= 16);
+    // ```
+    pub fn from_fn(width: i32, height: i32, usable: impl Fn(i32, i32) -> bool) -> Mask {
+        let mut cells: Vec<Vec<bool>> = Vec::new();
+        let mut y = 0;
+        while y < height {
+            let mut row: Vec<bool> = Vec::new();
+            let mut x = 0;
+            while x < width {
+                row.push(usable(x, y));
+                x += 1;
+            }
+            cells.push(row);
+            y += 1;
+        }
+        Mask { cells }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.cells.first().map_or(0, |row| row.len()) as i32
+    }
+
+    pub fn height(&self) -> i32 {
+        self.cells.len() as i32
+    }
+
+    // Whether a letter can go in the cell, cells outside the mask are not usable
+    pub fn is_usable(&self, x: i32, y: i32) -> bool {
+        x >= 0
+            && y >= 0
+            && self
+                .cells
+                .get(y as usize)
+                .and_then(|row| row.get(x as usize))
+                .copied()
+                .unwrap_or(false)
+    }
+
+    // The number of cells letters can go in
+    pub fn usable_cells(&self) -> i32 {
+        self.cells
+            .iter()
+            .map(|row| row.iter().filter(|cell| **cell).count() as i32)
+            .sum()
+    }
+
+    // Whether every row has the same length
+    pub fn is_rectangular(&self) -> bool {
+        self.cells
+            .iter()
+            .all(|row| row.len() as i32 == self.width())
+    }
+    // Parses a mask drawn as text, `#` is a cell and `.` is a hole,
+    // blank lines and spaces around the rows are ignored
+    //
+    // ```text
+    // .##.##.
+    // #######
+    // .#####.
+    // ..###..
+    // ...#...
+    // ```
+    pub fn from_ascii(art: &str) -> Result<Mask, WordSearchError> {
+        let mut cells: Vec<Vec<bool>> = Vec::new();
+        for line in art.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let mut row: Vec<bool> = Vec::new();
+            for c in line.chars() {
+                match c {
+                    '#' => row.push(true),
+                    '.' => row.push(false),
+                    _ => {
+                        return Err(invalid_mask(&format!(
+                            "unexpected {:?}, use '#' for cells and '.' for holes",
+                            c
+                        )))
+                    }
+                }
+            }
+            cells.push(row);
+        }
+        let mask = Mask { cells };
+        if mask.height() == 0 {
+            return Err(invalid_mask("no rows found"));
+        }
+        if !mask.is_rectangular() {
+            return Err(invalid_mask("rows must all have the same length"));
+        }
+        Ok(mask)
+    }
+
+    // Reads a black and white silhouette, dark pixels are cells, and scales
+    // it to `width` x `height`
+    //
+    // **Note:** PBM images are always supported, PNG images need the
+    // `png` feature
+    pub fn from_image(data: &[u8], width: i32, height: i32) -> Result<Mask, WordSearchError> {
+        let pixels = if data.starts_with(b"P1") || data.starts_with(b"P4") {
+            read_pbm(data)?
+        } else if data.starts_with(PNG_SIGNATURE) {
+            read_png(data)?
+        } else {
+            return Err(invalid_mask("only PBM and PNG images are supported"));
+        };
+        let image = Mask::new(pixels);
+        if image.width() == 0 || image.height() == 0 {
+            return Err(invalid_mask("the image is empty"));
+        }
+        Ok(image.scale(width, height))
+    }
+
+    // Stretches or shrinks the mask to `width` x `height`, a cell is usable
+    // when at least half of the area it covers is
+    pub fn scale(&self, width: i32, height: i32) -> Mask {
+        let source_width = self.width();
+        let source_height = self.height();
+        Mask::from_fn(width, height, |x, y| {
+            let x0 = x * source_width / width;
+            let x1 = ((x + 1) * source_width / width).max(x0 + 1);
+            let y0 = y * source_height / height;
+            let y1 = ((y + 1) * source_height / height).max(y0 + 1);
+            let mut usable = 0;
+            let mut total = 0;
+            for source_y in y0..y1 {
+                for source_x in x0..x1 {
+                    total += 1;
+                    if self.is_usable(source_x, source_y) {
+                        usable += 1;
+                    }
+                }
+            }
+            usable * 2 >= total
+        })
+    }
+}
+
+fn invalid_mask(reason: &str) -> WordSearchError {
+    WordSearchError::InvalidMask(String::from(reason))
+}
+
+// The next header token of a PBM image, tokens are separated
+// by whitespace and `#` starts a comment
+fn pbm_token<'a>(data: &'a [u8], position: &mut usize) -> Option<&'a str> {
+    while *position < data.len() {
+        if data[*position] == b'#' {
+            while *position < data.len() && data[*position] != b'\n' {
+                *position += 1;
+            }
+        } else if data[*position].is_ascii_whitespace() {
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+    let start = *position;
+    while *position < data.len() && !data[*position].is_ascii_whitespace() {
+        *position += 1;
+    }
+    std::str::from_utf8(&data[start..*position])
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+// Reads the pixels of a plain (`P1`) or raw (`P4`) PBM image, `true` for black
+fn read_pbm(data: &[u8]) -> Result<Vec<Vec<bool>>, WordSearchError> {
+    let mut position = 0;
+    let magic = pbm_token(data, &mut position);
+    let mut size = || {
+        pbm_token(data, &mut position)
+            .and_then(|token| token.parse::<usize>().ok())
+            .ok_or_else(|| invalid_mask("the PBM header is missing its size"))
+    };
+    let width = size()?;
+    let height = size()?;
+    if width == 0 || height == 0 {
+        return Err(invalid_mask("the PBM image has no pixels"));
+    }
+    let raw = magic == Some("P4");
+    // A single whitespace character separates the header from the rows
+    if raw {
+        position += 1;
+    }
+    let row_bytes = width.div_ceil(8);
+
+    // Check the size against the data before allocating the pixels,
+    // plain images take at least a byte per pixel
+    let needed = if raw {
+        row_bytes.checked_mul(height)
+    } else {
+        width.checked_mul(height)
+    };
+    if needed.is_none_or(|needed| data.len().saturating_sub(position) < needed) {
+        return Err(invalid_mask("the PBM image is missing pixels"));
+    }
+    let mut pixels: Vec<Vec<bool>> = vec![vec![false; width]; height];
+
+    if raw {
+        for (y, row) in pixels.iter_mut().enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                let byte = data[position + y * row_bytes + x / 8];
+                *pixel = byte & (0x80 >> (x % 8)) != 0;
+            }
+        }
+    } else {
+        // Plain pixels may or may not be separated by whitespace
+        let bits: Vec<bool> = data[position..]
+            .iter()
+            .filter(|byte| **byte == b'0' || **byte == b'1')
+            .map(|byte| *byte == b'1')
+            .collect();
+        if bits.len() < width * height {
+            return Err(invalid_mask("the PBM image is missing pixels"));
+        }
+        for (y, row) in pixels.iter_mut().enumerate() {
+            row.copy_from_slice(&bits[y * width..(y + 1) * width]);
+        }
+    }
+    Ok(pixels)
+}
+
+// Reads the pixels of a PNG image, `true` for dark and opaque pixels
+#[cfg(feature = "png")]
+fn read_png(data: &[u8]) -> Result<Vec<Vec<bool>>, WordSearchError> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder
+        .read_info()
+        .map_err(|error| invalid_mask(&error.to_string()))?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buffer)
+        .map_err(|error| invalid_mask(&error.to_string()))?;
+    let channels = info.color_type.samples();
+    let width = info.width as usize;
+    Ok(buffer[..info.buffer_size()]
+        .chunks(info.line_size)
+        .map(|line| {
+            line.chunks(channels)
+                .take(width)
+                .map(|pixel| {
+                    let (luma, alpha) = match pixel {
+                        [gray] => (u32::from(*gray), 255),
+                        [gray, alpha] => (u32::from(*gray), *alpha),
+                        [r, g, b] => ((u32::from(*r) + u32::from(*g) + u32::from(*b)) / 3, 255),
+                        [r, g, b, alpha, ..] => {
+                            ((u32::from(*r) + u32::from(*g) + u32::from(*b)) / 3, *alpha)
+                        }
+                        _ => (255, 0),
+                    };
+                    luma < 128 && alpha >= 128
+                })
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(not(feature = "png"))]
+fn read_png(_data: &[u8]) -> Result<Vec<Vec<bool>>, WordSearchError> {
+    Err(invalid_mask("PNG images need the `png` feature"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask() {
+        let mask = Mask::new(vec![
+            vec![true, false],
+            vec![true, true],
+            vec![false, false],
+        ]);
+        assert_eq!(mask.width(), 2);
+        assert_eq!(mask.height(), 3);
+        assert!(mask.is_usable(0, 0));
+        assert!(!mask.is_usable(1, 0));
+        assert!(!mask.is_usable(2, 0));
+        assert!(!mask.is_usable(0, -1));
+        assert_eq!(mask.usable_cells(), 3);
+        assert!(mask.is_rectangular());
+        assert!(!Mask::new(vec![vec![true], vec![true, true]]).is_rectangular());
+
+        assert_eq!(Mask::full(3, 2).usable_cells(), 6);
+        let diagonal = Mask::from_fn(3, 3, |x, y| x == y);
+        assert_eq!(diagonal.usable_cells(), 3);
+        assert!(diagonal.is_usable(2, 2));
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        let mask = Mask::from_ascii(
+            "
+            .#.
+            ###
+            .#.
+            ",
+        )
+        .unwrap();
+        assert_eq!(mask.width(), 3);
+        assert_eq!(mask.height(), 3);
+        assert_eq!(mask.usable_cells(), 5);
+        assert!(!mask.is_usable(0, 0));
+        assert!(mask.is_usable(1, 0));
+
+        assert_eq!(Mask::from_ascii("#x#").unwrap_err().code(), "INVALID_MASK");
+        assert_eq!(
+            Mask::from_ascii("##\n#").unwrap_err().code(),
+            "INVALID_MASK"
+        );
+        assert_eq!(Mask::from_ascii("\n").unwrap_err().code(), "INVALID_MASK");
+    }
+
+    #[test]
+    fn test_scale() {
+        let mask = Mask::from_ascii("#.\n..").unwrap();
+        let larger = mask.scale(4, 4);
+        assert_eq!(larger.usable_cells(), 4);
+        assert!(larger.is_usable(1, 1));
+        assert!(!larger.is_usable(2, 1));
+
+        let smaller = Mask::full(6, 6).scale(3, 2);
+        assert_eq!(smaller, Mask::full(3, 2));
+    }
+
+    #[test]
+    fn test_from_pbm() {
+        let plain = b"P1\n# a comment\n3 2\n0 1 0\n1 1 1\n";
+        let mask = Mask::from_image(plain, 3, 2).unwrap();
+        assert_eq!(
+            mask,
+            Mask::new(vec![vec![false, true, false], vec![true, true, true]])
+        );
+
+        let raw = b"P4\n3 2\n\x40\xe0";
+        assert_eq!(Mask::from_image(raw, 3, 2).unwrap(), mask);
+        assert_eq!(Mask::from_image(raw, 6, 4).unwrap().usable_cells(), 16);
+
+        assert_eq!(
+            Mask::from_image(b"P1\n3 2\n0 1", 3, 2).unwrap_err().code(),
+            "INVALID_MASK"
+        );
+        // Sizes larger than the data are rejected before allocating
+        assert_eq!(
+            Mask::from_image(b"P1\n0 18446744073709551615\n", 3, 3)
+                .unwrap_err()
+                .code(),
+            "INVALID_MASK"
+        );
+        assert_eq!(
+            Mask::from_image(b"P1\n100000000000 0\n", 3, 3)
+                .unwrap_err()
+                .code(),
+            "INVALID_MASK"
+        );
+        assert_eq!(
+            Mask::from_image(b"P1\n4000000000 4000000000\n0", 3, 3)
+                .unwrap_err()
+                .code(),
+            "INVALID_MASK"
+        );
+        assert_eq!(
+            Mask::from_image(b"P4\n18446744073709551615 9\n\0", 3, 3)
+                .unwrap_err()
+                .code(),
+            "INVALID_MASK"
+        );
+        assert_eq!(
+            Mask::from_image(b"GIF89a", 3, 2).unwrap_err().code(),
+            "INVALID_MASK"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn test_from_png() {
+        let mut data: Vec<u8> = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut data, 2, 2);
+            encoder.set_color(png::ColorType::Grayscale);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().unwrap();
+            writer.write_image_data(&[0, 255, 255, 0]).unwrap();
+        }
+        let mask = Mask::from_image(&data, 4, 4).unwrap();
+        assert_eq!(mask.usable_cells(), 8);
+        assert!(mask.is_usable(0, 0));
+        assert!(!mask.is_usable(2, 0));
+        assert!(mask.is_usable(3, 3));
+    }
+
+    #[test]
+    #[cfg(not(feature = "png"))]
+    fn test_from_png() {
+        assert_eq!(
+            Mask::from_image(PNG_SIGNATURE, 4, 4).unwrap_err().code(),
+            "INVALID_MASK"
+        );
+    }
+}
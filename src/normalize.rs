@@ -0,0 +1,195 @@
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseFolding {
+    // Keep the case of the word as given
+    Preserve,
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accents {
+    // Keep accented letters such as `é`
+    Preserve,
+    // Replace accented letters with the plain letter, `é` becomes `e`
+    Strip,
+}
+
+// Steps applied to every word before it is placed in the puzzle
+//
+// **Note:** Only the normalized form goes into the grid, the word
+// bank and answer key keep the word as it was given
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalization {
+    pub case: CaseFolding,
+    // Remove spaces so "ice cream" is placed as "icecream"
+    pub strip_spaces: bool,
+    // Remove punctuation so "rock'n'roll" is placed as "rocknroll"
+    pub strip_punctuation: bool,
+    pub accents: Accents,
+    // Expand ligatures such as `æ` and `ﬁ` into separate letters
+    pub expand_ligatures: bool,
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization {
+            case: CaseFolding::Preserve,
+            strip_spaces: true,
+            strip_punctuation: true,
+            accents: Accents::Preserve,
+            expand_ligatures: false,
+        }
+    }
+}
+
+// Punctuation found in word lists, the ASCII set is checked separately
+const PUNCTUATION: &str = "‘’‚‛“”„‟‐‑‒–—―…·•′″‹›«»¡¿、。・";
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || PUNCTUATION.contains(c)
+}
+
+fn ligature(c: char) -> Option<&'static str> {
+    match c {
+        'æ' => Some("ae"),
+        'Æ' => Some("AE"),
+        'œ' => Some("oe"),
+        'Œ' => Some("OE"),
+        'ĳ' => Some("ij"),
+        'Ĳ' => Some("IJ"),
+        'ﬀ' => Some("ff"),
+        'ﬁ' => Some("fi"),
+        'ﬂ' => Some("fl"),
+        'ﬃ' => Some("ffi"),
+        'ﬄ' => Some("ffl"),
+        'ﬅ' | 'ﬆ' => Some("st"),
+        _ => None,
+    }
+}
+
+// Combining diacritical marks, limited to the block used by Latin, Greek
+// and Cyrillic so vowel signs in scripts like Devanagari are kept
+fn is_accent(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+impl Normalization {
+    // Leaves words exactly as they are given
+    pub fn none() -> Normalization {
+        Normalization {
+            case: CaseFolding::Preserve,
+            strip_spaces: false,
+            strip_punctuation: false,
+            accents: Accents::Preserve,
+            expand_ligatures: false,
+        }
+    }
+
+    pub fn apply(&self, word: &str) -> String {
+        let mut text: String = word.nfc().collect();
+        if self.expand_ligatures {
+            text = text
+                .chars()
+                .map(|c| match ligature(c) {
+                    Some(expanded) => String::from(expanded),
+                    None => c.to_string(),
+                })
+                .collect();
+        }
+        if self.accents == Accents::Strip {
+            text = text.nfd().filter(|c| !is_accent(*c)).nfc().collect();
+        }
+        text = match self.case {
+            CaseFolding::Preserve => text,
+            CaseFolding::Lower => text.to_lowercase(),
+            CaseFolding::Upper => text.to_uppercase(),
+        };
+        if self.strip_spaces {
+            text = text.chars().filter(|c| !c.is_whitespace()).collect();
+        }
+        if self.strip_punctuation {
+            text = text.chars().filter(|c| !is_punctuation(*c)).collect();
+        }
+        text
+    }
+}
+
+// A word from the word list along with the form placed in the grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct Word {
+    // The word as given, used for the word bank
+    pub display: String,
+    // The normalized word placed in the grid
+    pub text: String,
+}
+
+impl Word {
+    pub fn new(display: &str, normalization: &Normalization) -> Word {
+        Word {
+            display: String::from(display),
+            text: normalization.apply(display),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_normalization() {
+        let normalization = Normalization::default();
+        assert_eq!(normalization.apply("ice cream"), "icecream");
+        assert_eq!(normalization.apply("rock'n'roll"), "rocknroll");
+        assert_eq!(normalization.apply("T-Rex"), "TRex");
+        assert_eq!(normalization.apply("café"), "café");
+        assert_eq!(normalization.apply("नमस्ते"), "नमस्ते");
+    }
+
+    #[test]
+    fn test_none() {
+        assert_eq!(
+            Normalization::none().apply("Rock 'n' Roll"),
+            "Rock 'n' Roll"
+        );
+    }
+
+    #[test]
+    fn test_case_and_accents() {
+        let normalization = Normalization {
+            case: CaseFolding::Upper,
+            accents: Accents::Strip,
+            ..Normalization::default()
+        };
+        assert_eq!(normalization.apply("Crème brûlée"), "CREMEBRULEE");
+        // Decomposed input is handled the same way
+        assert_eq!(normalization.apply("cafe\u{301}"), "CAFE");
+
+        let normalization = Normalization {
+            case: CaseFolding::Lower,
+            ..Normalization::default()
+        };
+        assert_eq!(normalization.apply("Ça Va"), "çava");
+        assert_eq!(normalization.apply("cafe\u{301}"), "café");
+    }
+
+    #[test]
+    fn test_ligatures() {
+        let normalization = Normalization {
+            expand_ligatures: true,
+            ..Normalization::default()
+        };
+        assert_eq!(normalization.apply("encyclopædia"), "encyclopaedia");
+        assert_eq!(normalization.apply("œuvre"), "oeuvre");
+        assert_eq!(normalization.apply("ﬁsh"), "fish");
+    }
+
+    #[test]
+    fn test_word() {
+        let word = Word::new("ice cream", &Normalization::default());
+        assert_eq!(word.display, "ice cream");
+        assert_eq!(word.text, "icecream");
+    }
+}
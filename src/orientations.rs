@@ -1,11 +1,59 @@
 use crate::position::Position;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Orientation {
+    // Left to right
     Horizontal,
+    // Top to bottom
     Vertical,
+    // Top left to bottom right
     Diagonal,
+    // Bottom left to top right
     DiagonalUp,
+    // Right to left
+    HorizontalBack,
+    // Bottom to top
+    VerticalUp,
+    // Bottom right to top left
+    DiagonalBack,
+    // Top right to bottom left
+    DiagonalUpBack,
+}
+
+// Every orientation a word can be read in
+pub const ALL_ORIENTATIONS: [Orientation; 8] = [
+    Orientation::Horizontal,
+    Orientation::Vertical,
+    Orientation::Diagonal,
+    Orientation::DiagonalUp,
+    Orientation::HorizontalBack,
+    Orientation::VerticalUp,
+    Orientation::DiagonalBack,
+    Orientation::DiagonalUpBack,
+];
+
+impl Orientation {
+    // Name used for the orientation outside of Rust
+    pub fn name(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+            Orientation::Diagonal => "diagonal",
+            Orientation::DiagonalUp => "diagonalUp",
+            Orientation::HorizontalBack => "horizontalBack",
+            Orientation::VerticalUp => "verticalUp",
+            Orientation::DiagonalBack => "diagonalBack",
+            Orientation::DiagonalUpBack => "diagonalUpBack",
+        }
+    }
+
+    // Looks up an orientation by its `name`
+    pub fn from_name(name: &str) -> Option<Orientation> {
+        ALL_ORIENTATIONS
+            .iter()
+            .find(|orientation| orientation.name() == name)
+            .copied()
+    }
 }
 
 pub fn get_orientation(orientation: &Orientation) -> Box<dyn Fn(i32, i32, i32) -> Position> {
@@ -14,6 +62,10 @@ pub fn get_orientation(orientation: &Orientation) -> Box<dyn Fn(i32, i32, i32) -
         Orientation::Vertical => Box::new(|x, y, i| Position::from(x, y + i)),
         Orientation::Diagonal => Box::new(|x, y, i| Position::from(x + i, y + i)),
         Orientation::DiagonalUp => Box::new(|x, y, i| Position::from(x + i, y - i)),
+        Orientation::HorizontalBack => Box::new(|x, y, i| Position::from(x - i, y)),
+        Orientation::VerticalUp => Box::new(|x, y, i| Position::from(x, y - i)),
+        Orientation::DiagonalBack => Box::new(|x, y, i| Position::from(x - i, y - i)),
+        Orientation::DiagonalUpBack => Box::new(|x, y, i| Position::from(x - i, y + i)),
     }
 }
 
@@ -25,17 +77,37 @@ pub fn check_orientation(
         Orientation::Vertical => Box::new(|_x, y, h, _w, l| h >= y + l),
         Orientation::Diagonal => Box::new(|x, y, h, w, l| w >= x + l && h >= y + l),
         Orientation::DiagonalUp => Box::new(|x, y, _h, w, l| w >= x + l && y + 1 >= l),
+        Orientation::HorizontalBack => Box::new(|x, _y, _h, _w, l| x + 1 >= l),
+        Orientation::VerticalUp => Box::new(|_x, y, _h, _w, l| y + 1 >= l),
+        Orientation::DiagonalBack => Box::new(|x, y, _h, _w, l| x + 1 >= l && y + 1 >= l),
+        Orientation::DiagonalUpBack => Box::new(|x, y, h, _w, l| x + 1 >= l && h >= y + l),
     }
 }
 
 pub fn skip_orientation(orientation: &Orientation) -> Box<dyn Fn(i32, i32, i32) -> Position> {
     match orientation {
-        Orientation::Horizontal => Box::new(|x, y, _l| Position::from(x, y + 1)),
+        Orientation::Horizontal => Box::new(|_x, y, _l| Position::from(0, y + 1)),
         Orientation::Vertical => Box::new(|_x, y, _l| Position::from(0, y + 100)),
         Orientation::Diagonal => Box::new(|_x, y, _l| Position::from(0, y + 1)),
         Orientation::DiagonalUp => {
             Box::new(|_x, y, l| Position::from(0, if y < l - 1 { l - 1 } else { y + 1 }))
         }
+        Orientation::HorizontalBack => Box::new(|_x, y, l| Position::from(l - 1, y)),
+        Orientation::VerticalUp => Box::new(|_x, _y, l| Position::from(0, l - 1)),
+        Orientation::DiagonalBack => Box::new(|_x, y, l| {
+            if y < l - 1 {
+                Position::from(0, l - 1)
+            } else {
+                Position::from(l - 1, y)
+            }
+        }),
+        Orientation::DiagonalUpBack => Box::new(|x, y, l| {
+            if x < l - 1 {
+                Position::from(l - 1, y)
+            } else {
+                Position::from(0, y + 1)
+            }
+        }),
     }
 }
 
@@ -43,6 +115,17 @@ pub fn skip_orientation(orientation: &Orientation) -> Box<dyn Fn(i32, i32, i32)
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_orientation_names() {
+        for orientation in ALL_ORIENTATIONS.iter() {
+            assert_eq!(
+                Orientation::from_name(orientation.name()),
+                Some(*orientation)
+            );
+        }
+        assert_eq!(Orientation::from_name("sideways"), None);
+    }
+
     #[test]
     fn test_get_orientation() {
         let orientation_h = Orientation::Horizontal;
@@ -59,27 +142,63 @@ mod tests {
         let position_d: Position = get_orientation(&orientation_d)(1, 2, 3);
         assert_eq!(position_d.x, 4);
         assert_eq!(position_d.y, 5);
+
+        let orientation_hb = Orientation::HorizontalBack;
+        let position_hb: Position = get_orientation(&orientation_hb)(4, 2, 3);
+        assert_eq!(position_hb.x, 1);
+        assert_eq!(position_hb.y, 2);
+
+        let orientation_vu = Orientation::VerticalUp;
+        let position_vu: Position = get_orientation(&orientation_vu)(1, 4, 3);
+        assert_eq!(position_vu.x, 1);
+        assert_eq!(position_vu.y, 1);
+
+        let orientation_db = Orientation::DiagonalBack;
+        let position_db: Position = get_orientation(&orientation_db)(4, 4, 3);
+        assert_eq!(position_db.x, 1);
+        assert_eq!(position_db.y, 1);
+
+        let orientation_dub = Orientation::DiagonalUpBack;
+        let position_dub: Position = get_orientation(&orientation_dub)(4, 1, 3);
+        assert_eq!(position_dub.x, 1);
+        assert_eq!(position_dub.y, 4);
     }
 
     #[test]
     fn test_check_orientation() {
         let orientation_h = Orientation::Horizontal;
-        assert_eq!(check_orientation(&orientation_h)(1, 2, 3, 5, 4), true);
-        assert_eq!(check_orientation(&orientation_h)(1, 2, 3, 5, 3), true);
+        assert!(check_orientation(&orientation_h)(1, 2, 3, 5, 4));
+        assert!(check_orientation(&orientation_h)(1, 2, 3, 5, 3));
 
         let orientation_v = Orientation::Vertical;
-        assert_eq!(check_orientation(&orientation_v)(1, 1, 5, 5, 4), true);
-        assert_eq!(check_orientation(&orientation_v)(1, 1, 5, 5, 3), true);
+        assert!(check_orientation(&orientation_v)(1, 1, 5, 5, 4));
+        assert!(check_orientation(&orientation_v)(1, 1, 5, 5, 3));
 
         let orientation_d = Orientation::Diagonal;
-        assert_eq!(check_orientation(&orientation_d)(1, 1, 5, 5, 4), true);
+        assert!(check_orientation(&orientation_d)(1, 1, 5, 5, 4));
+
+        let orientation_hb = Orientation::HorizontalBack;
+        assert!(check_orientation(&orientation_hb)(3, 0, 5, 5, 4));
+        assert!(!check_orientation(&orientation_hb)(2, 0, 5, 5, 4));
+
+        let orientation_vu = Orientation::VerticalUp;
+        assert!(check_orientation(&orientation_vu)(0, 3, 5, 5, 4));
+        assert!(!check_orientation(&orientation_vu)(0, 2, 5, 5, 4));
+
+        let orientation_db = Orientation::DiagonalBack;
+        assert!(check_orientation(&orientation_db)(3, 3, 5, 5, 4));
+        assert!(!check_orientation(&orientation_db)(3, 2, 5, 5, 4));
+
+        let orientation_dub = Orientation::DiagonalUpBack;
+        assert!(check_orientation(&orientation_dub)(3, 1, 5, 5, 4));
+        assert!(!check_orientation(&orientation_dub)(3, 2, 5, 5, 4));
     }
 
     #[test]
     fn test_skip_orientation() {
         let orientation_h = Orientation::Horizontal;
         let position_h: Position = skip_orientation(&orientation_h)(1, 2, 3);
-        assert_eq!(position_h.x, 1);
+        assert_eq!(position_h.x, 0);
         assert_eq!(position_h.y, 3);
 
         let orientation_v = Orientation::Vertical;
@@ -91,5 +210,23 @@ mod tests {
         let position_d: Position = skip_orientation(&orientation_d)(1, 2, 3);
         assert_eq!(position_d.x, 0);
         assert_eq!(position_d.y, 3);
+
+        let orientation_hb = Orientation::HorizontalBack;
+        let position_hb: Position = skip_orientation(&orientation_hb)(0, 2, 3);
+        assert_eq!(position_hb.x, 2);
+        assert_eq!(position_hb.y, 2);
+
+        let orientation_db = Orientation::DiagonalBack;
+        let position_db: Position = skip_orientation(&orientation_db)(0, 0, 3);
+        assert_eq!(position_db.x, 0);
+        assert_eq!(position_db.y, 2);
+        let position_db: Position = skip_orientation(&orientation_db)(0, 2, 3);
+        assert_eq!(position_db.x, 2);
+        assert_eq!(position_db.y, 2);
+
+        let orientation_dub = Orientation::DiagonalUpBack;
+        let position_dub: Position = skip_orientation(&orientation_dub)(2, 3, 3);
+        assert_eq!(position_dub.x, 0);
+        assert_eq!(position_dub.y, 4);
     }
 }
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Position {
     // The col where the word starts
     pub x: i32,
@@ -22,4 +22,4 @@ mod tests {
         assert_eq!(p.x, 1);
         assert_eq!(p.y, 2);
     }
-}
\ No newline at end of file
+}
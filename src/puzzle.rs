@@ -1,30 +1,32 @@
+// Rows of cells, every cell holds one letter (grapheme cluster)
+// and is empty until a letter is placed in it
+pub type Grid = Vec<Vec<String>>;
+
 pub struct Puzzle {
     // Two dimentional list containing the puzzle
-    pub puzzle: Vec<Vec<char>>,
+    pub puzzle: Grid,
     // List of word not placed in the puzzle
     pub words_not_placed: Vec<String>,
+    // List of words left out to reach the target density
+    pub words_not_used: Vec<String>,
     // List of warnings that occured while creating the puzzle
     //
     // **Note:** Use this to notify the user of any issues
     pub warnings: Vec<String>,
-    // List of errors that occured while creating the puzzle
-    //
-    // **Note:** Check this before printing or viewing the puzzle
-    pub errors: Vec<String>,
 }
 
 impl Puzzle {
     pub fn new(w: i32, h: i32) -> Puzzle {
         Puzzle {
-            puzzle: vec![vec![' '; w as usize]; h as usize],
+            puzzle: vec![vec![String::new(); w as usize]; h as usize],
             words_not_placed: vec![],
+            words_not_used: vec![],
             warnings: vec![],
-            errors: vec![],
         }
     }
 
     pub fn place_char(&mut self, character: char, x: i32, y: i32) {
-        self.puzzle[x as usize][y as usize] = character;
+        self.puzzle[x as usize][y as usize] = character.to_string();
     }
 
     pub fn add_words_not_placed(&mut self, word: &str) {
@@ -34,10 +36,6 @@ impl Puzzle {
     pub fn add_warning(&mut self, warning: &str) {
         self.warnings.push(String::from(warning));
     }
-
-    pub fn add_errors(&mut self, error: &str) {
-        self.errors.push(String::from(error));
-    }
 }
 
 #[cfg(test)]
@@ -58,7 +56,7 @@ mod tests {
     fn test_place_char() {
         let mut puzzle = Puzzle::new(5, 5);
         puzzle.place_char('a', 1, 1);
-        assert_eq!(puzzle.puzzle[1][1], 'a');
+        assert_eq!(puzzle.puzzle[1][1], "a");
     }
 
     #[test]
@@ -74,11 +72,4 @@ mod tests {
         puzzle.add_warning("test");
         assert_eq!(puzzle.warnings[0], "test");
     }
-
-    #[test]
-    fn test_add_errors() {
-        let mut puzzle = Puzzle::new(5, 5);
-        puzzle.add_errors("test");
-        assert_eq!(puzzle.errors[0], "test");
-    }
 }
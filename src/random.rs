@@ -0,0 +1,108 @@
+// Source of randomness used while generating a puzzle
+//
+// **Note:** Implement this to plug in a custom generator, the
+// default is the seeded `Rng` below
+pub trait RandomSource {
+    fn next_u64(&mut self) -> u64;
+
+    // Restarts the sequence, called at the start of every `create`
+    fn reseed(&mut self, _seed: u64) {}
+
+    // Returns a number in the range `0..max`
+    fn gen_range(&mut self, max: usize) -> usize {
+        (self.next_u64() % max as u64) as usize
+    }
+}
+
+// Small deterministic pseudo random number generator (SplitMix64)
+//
+// **Note:** The sequence only depends on the seed, so the same seed
+// always produces the same puzzle on every platform
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    // Constructor
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+}
+
+impl RandomSource for Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.state = seed;
+    }
+}
+
+// Picks a seed for puzzles that were not given one
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub fn random_seed() -> u64 {
+    crate::wasm::JsRandom.next_u64()
+}
+
+// Picks a seed for puzzles that were not given one
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+pub fn random_seed() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    // There is no clock or entropy without a JS host, so
+    // only make consecutive puzzles differ from each other
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    Rng::new(COUNTER.fetch_add(1, Ordering::Relaxed)).next_u64()
+}
+
+// Picks a seed for puzzles that were not given one
+#[cfg(not(target_arch = "wasm32"))]
+pub fn random_seed() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or(0);
+    Rng::new(nanos).next_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut i = 0;
+        while i < 10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+            i += 1;
+        }
+    }
+
+    #[test]
+    fn test_known_sequence() {
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+
+        rng.reseed(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = Rng::new(7);
+        let mut i = 0;
+        while i < 100 {
+            assert!(rng.gen_range(5) < 5);
+            i += 1;
+        }
+    }
+}
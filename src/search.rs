@@ -0,0 +1,118 @@
+use crate::location::Location;
+use crate::orientations::*;
+use crate::position::Position;
+use crate::utils::letters;
+
+// Finds every place the word can be read in the puzzle in the given
+// orientations, ordered by orientation and then by row and column, with
+// `wrap` words can also run off one edge and continue from the opposite one
+//
+// **Note:** A word read over the same cells in two orientations, such
+// as a palindrome, is only returned once
+pub fn find_occurrences(
+    puzzle: &[Vec<String>],
+    word: &str,
+    orientations: &[Orientation],
+    wrap: bool,
+) -> Vec<Location> {
+    let letters = letters(word);
+    let word_len = letters.len() as i32;
+    let height = puzzle.len() as i32;
+    let width = if height > 0 {
+        puzzle[0].len() as i32
+    } else {
+        0
+    };
+    let mut occurrences: Vec<Location> = Vec::new();
+    let mut seen_cells: Vec<Vec<Position>> = Vec::new();
+
+    for orientation in orientations.iter() {
+        let check = check_orientation(orientation);
+        let mut y: i32 = 0;
+        while y < height {
+            let mut x: i32 = 0;
+            while x < width {
+                let mut location = Location::from(x, y, 0, *orientation, word);
+                if wrap {
+                    location = location.wrap_around(width, height);
+                }
+                let fits = if wrap {
+                    !location.overlaps_itself()
+                } else {
+                    check(x, y, height, width, word_len)
+                };
+                if fits
+                    && location
+                        .cells()
+                        .iter()
+                        .zip(letters.iter())
+                        .all(|(position, letter)| {
+                            puzzle[position.y as usize][position.x as usize] == *letter
+                        })
+                {
+                    let mut cells = location.cells();
+                    cells.sort();
+                    if !seen_cells.contains(&cells) {
+                        seen_cells.push(cells);
+                        occurrences.push(location);
+                    }
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+    }
+    occurrences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| letters(row).into_iter().map(String::from).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_find_occurrences() {
+        let puzzle = grid(&["abax", "xbxx", "axxx", "xxxx"]);
+        let occurrences = find_occurrences(&puzzle, "ab", &ALL_ORIENTATIONS, false);
+        let found: Vec<(i32, i32, Orientation)> = occurrences
+            .iter()
+            .map(|location| (location.x, location.y, location.orientation))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (0, 0, Orientation::Horizontal),
+                (0, 0, Orientation::Diagonal),
+                (0, 2, Orientation::DiagonalUp),
+                (2, 0, Orientation::HorizontalBack),
+                (2, 0, Orientation::DiagonalUpBack),
+            ]
+        );
+        assert!(find_occurrences(&puzzle, "ab", &[Orientation::Vertical], false).is_empty());
+    }
+
+    #[test]
+    fn test_palindrome_found_once() {
+        let puzzle = grid(&["abba"]);
+        assert_eq!(
+            find_occurrences(&puzzle, "abba", &ALL_ORIENTATIONS, false).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_find_wrapped_occurrences() {
+        let puzzle = grid(&["bxxa", "xxxx", "xxxx", "xxxx"]);
+        let horizontal = [Orientation::Horizontal];
+        assert!(find_occurrences(&puzzle, "ab", &horizontal, false).is_empty());
+        let occurrences = find_occurrences(&puzzle, "ab", &horizontal, true);
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!((occurrences[0].x, occurrences[0].y), (3, 0));
+        assert_eq!(occurrences[0].wrap, Some((4, 4)));
+    }
+}
@@ -1,25 +1,178 @@
+use crate::blocklist::default_forbidden_words;
+use crate::error::WordSearchError;
+use crate::filler::FillerMode;
+use crate::mask::Mask;
+use crate::normalize::Normalization;
 use crate::orientations::*;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    // Place words where they share the most letters with other words
+    Prefer,
+    // Place words where they share the fewest letters with other words
+    Avoid,
+    // Never let words share letters
+    Disallow,
+}
+
+impl OverlapPolicy {
+    // Name used for the policy outside of Rust
+    pub fn name(&self) -> &'static str {
+        match self {
+            OverlapPolicy::Prefer => "prefer",
+            OverlapPolicy::Avoid => "avoid",
+            OverlapPolicy::Disallow => "disallow",
+        }
+    }
+
+    // Looks up a policy by its `name`
+    pub fn from_name(name: &str) -> Option<OverlapPolicy> {
+        [
+            OverlapPolicy::Prefer,
+            OverlapPolicy::Avoid,
+            OverlapPolicy::Disallow,
+        ]
+        .iter()
+        .find(|policy| policy.name() == name)
+        .copied()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    // Remove words that are duplicates of, or hidden inside, other words
+    // and add a warning naming each removed word to the puzzle
+    Drop,
+    // Keep the words and add a warning to the puzzle
+    //
+    // **Note:** Words given twice with the same text are still removed,
+    // the answer key has one entry per word
+    Warn,
+    // Refuse to create the puzzle
+    Fail,
+}
+
+impl DuplicatePolicy {
+    // Name used for the policy outside of Rust
+    pub fn name(&self) -> &'static str {
+        match self {
+            DuplicatePolicy::Drop => "drop",
+            DuplicatePolicy::Warn => "warn",
+            DuplicatePolicy::Fail => "fail",
+        }
+    }
+
+    // Looks up a policy by its `name`
+    pub fn from_name(name: &str) -> Option<DuplicatePolicy> {
+        [
+            DuplicatePolicy::Drop,
+            DuplicatePolicy::Warn,
+            DuplicatePolicy::Fail,
+        ]
+        .iter()
+        .find(|policy| policy.name() == name)
+        .copied()
+    }
+}
+
+// A word written at a fixed position before the other words are placed
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinnedWord {
+    // The word as given, it is normalized like the other words
+    pub word: String,
+    pub x: i32,
+    pub y: i32,
+    pub orientation: Orientation,
+}
+
+impl PinnedWord {
+    pub fn new(word: &str, x: i32, y: i32, orientation: Orientation) -> PinnedWord {
+        PinnedWord {
+            word: String::from(word),
+            x,
+            y,
+            orientation,
+        }
+    }
+}
+
 pub struct Settings {
     // The recommended width of the puzzle
-    ///
-    // **Note:** This will automatically increment if
-    // the words cannot be placed properly in the puzzle
+    //
+    // **Note:** This will automatically increment if the words cannot
+    // be placed properly in the puzzle, unless `fixed_size` is set
     pub width: i32,
     // The recommended height of the puzzle
-    ///
-    // **Note:** This will automatically increment if
-    // the words cannot be placed properly in the puzzle
+    //
+    // **Note:** This will automatically increment if the words cannot
+    // be placed properly in the puzzle, unless `fixed_size` is set
     pub height: i32,
+    // Never grow the grid, words that do not fit are left out
+    // and listed in `Puzzle::words_not_placed` instead
+    pub fixed_size: bool,
+    // Shape of the grid, the width and height are taken from the
+    // mask and the grid never grows when one is set
+    pub mask: Option<Mask>,
+    // Leave out the words that do not fit once the grid cannot grow
+    // any further, instead of failing to create the puzzle
+    pub best_effort: bool,
+    // Share of the cells that should hold word letters, between 0 and 1
+    //
+    // **Note:** The grid is shrunk while the words would still fit at this
+    // density and words are picked at random when there are too many
+    pub target_density: Option<f64>,
+    // The widest the grid can grow to
+    pub max_width: Option<i32>,
+    // The tallest the grid can grow to
+    pub max_height: Option<i32>,
+    // Let words run off one edge of the grid and continue from the
+    // opposite edge, as if the grid was wrapped around a torus
+    //
+    // **Note:** Pinned words never wrap
+    pub wrap_around: bool,
     // The allowed orientations for the words placed in the puzzle
     pub orientations: Vec<Orientation>,
-    // The allowed orientations for the words placed in the puzzle
+    // Number of times the words are placed from scratch at each grid
+    // size before the grid grows, or words are left out when it cannot
     pub max_attempts: i32,
+    // Maximum number of placements undone at each grid size to make
+    // room for the remaining words, 0 never moves a placed word
+    pub max_backtracks: i32,
     // Maximum numbed of times the grid can grow
     // depending on the length of the words and placement
     pub max_grid_growth: i32,
-    // Allow overlaping of words in the puzzle
-    pub prefer_overlap: bool,
+    // How words are allowed to share letters in the puzzle
+    pub overlap: OverlapPolicy,
+    // Seed for the random number generator
+    //
+    // **Note:** The same seed, words and settings always create
+    // the same puzzle, a random seed is picked when not set
+    pub seed: Option<u64>,
+    // The letters used to fill the cells not taken by words
+    pub filler_alphabet: String,
+    // How often each filler letter is drawn
+    pub filler_mode: FillerMode,
+    // How words are cleaned up before they are placed in the puzzle
+    pub normalization: Normalization,
+    // What to do with duplicate words and words hidden inside other words
+    pub duplicates: DuplicatePolicy,
+    // Re-roll filler letters so every word can only be found once
+    pub unique_words: bool,
+    // Words written at fixed positions before the other words are placed,
+    // they are added to the word list and other words can share their letters
+    pub pinned_words: Vec<PinnedWord>,
+    // Message written in reading order into the cells not taken by words,
+    // normalized like the words, instead of random filler letters
+    //
+    // **Note:** Exactly as many cells as the message has letters are left
+    // blank, the grid only grows while that would not leave too many
+    pub hidden_message: Option<String>,
+    // Words that must never be spelled by the filler letters in any
+    // orientation, matched after normalization and ignoring case
+    //
+    // **Note:** Defaults to the bundled list when the
+    // `profanity-filter` feature is enabled
+    pub forbidden_words: Vec<String>,
 }
 
 impl Default for Settings {
@@ -27,6 +180,13 @@ impl Default for Settings {
         Settings {
             width: 10,
             height: 10,
+            fixed_size: false,
+            mask: None,
+            best_effort: false,
+            target_density: None,
+            max_width: None,
+            max_height: None,
+            wrap_around: false,
             orientations: vec![
                 Orientation::Horizontal,
                 Orientation::Vertical,
@@ -34,8 +194,18 @@ impl Default for Settings {
                 Orientation::DiagonalUp,
             ],
             max_attempts: 10,
+            max_backtracks: 1000,
             max_grid_growth: 10,
-            prefer_overlap: false,
+            overlap: OverlapPolicy::Prefer,
+            seed: None,
+            filler_alphabet: String::from("abcdefghijklmnopqrstuvwxyz"),
+            filler_mode: FillerMode::Uniform,
+            normalization: Normalization::default(),
+            duplicates: DuplicatePolicy::Warn,
+            unique_words: true,
+            pinned_words: Vec::new(),
+            hidden_message: None,
+            forbidden_words: default_forbidden_words(),
         }
     }
 }
@@ -48,6 +218,86 @@ impl Settings {
     pub fn set_height(&mut self, height: i32) {
         self.height = height;
     }
+
+    // Checks the settings can be used to create a puzzle
+    pub fn validate(&self) -> Result<(), WordSearchError> {
+        let invalid = |reason: &str| Err(WordSearchError::InvalidSettings(String::from(reason)));
+        if self.width < 1 || self.height < 1 {
+            return invalid("width and height must be at least 1");
+        }
+        if self
+            .max_width
+            .is_some_and(|max_width| max_width < self.width)
+        {
+            return invalid("max_width cannot be less than width");
+        }
+        if self
+            .max_height
+            .is_some_and(|max_height| max_height < self.height)
+        {
+            return invalid("max_height cannot be less than height");
+        }
+        if self
+            .target_density
+            .is_some_and(|density| !(density > 0.0 && density <= 1.0))
+        {
+            return invalid("target_density must be above 0 and at most 1");
+        }
+        if let Some(mask) = &self.mask {
+            if mask.height() < 1 || mask.width() < 1 || !mask.is_rectangular() {
+                return invalid("mask rows must all have the same, non-zero length");
+            }
+            if mask.usable_cells() < 1 {
+                return invalid("mask must have at least one usable cell");
+            }
+        }
+        if self.orientations.is_empty() {
+            return invalid("at least one orientation is required");
+        }
+        if self.max_attempts < 1 {
+            return invalid("max_attempts must be at least 1");
+        }
+        if self.max_backtracks < 0 {
+            return invalid("max_backtracks cannot be negative");
+        }
+        if self.max_grid_growth < 0 {
+            return invalid("max_grid_growth cannot be negative");
+        }
+        if self.filler_alphabet.is_empty() {
+            return invalid("filler_alphabet cannot be empty");
+        }
+        if self
+            .filler_alphabet
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control())
+        {
+            return invalid("filler_alphabet cannot contain whitespace or control characters");
+        }
+        Ok(())
+    }
+
+    // Whether the grid can grow when the words do not fit
+    pub fn can_grow(&self) -> bool {
+        !self.fixed_size && self.mask.is_none()
+    }
+
+    // The largest width and height the grid can have, or `None` when
+    // nothing limits how far it grows
+    //
+    // **Note:** The grid always widens to fit the longest word, so
+    // `max_grid_growth` is not a limit here
+    pub fn max_size(&self) -> Option<(i32, i32)> {
+        if !self.can_grow() {
+            return Some((self.width, self.height));
+        }
+        if self.max_width.is_none() && self.max_height.is_none() {
+            return None;
+        }
+        Some((
+            self.max_width.unwrap_or(i32::MAX),
+            self.max_height.unwrap_or(i32::MAX),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -59,9 +309,26 @@ mod tests {
         let mut settings = Settings::default();
         assert_eq!(settings.width, 10);
         assert_eq!(settings.height, 10);
+        assert!(!settings.fixed_size);
+        assert_eq!(settings.mask, None);
+        assert!(!settings.best_effort);
+        assert_eq!(settings.target_density, None);
+        assert_eq!(settings.max_width, None);
+        assert_eq!(settings.max_height, None);
+        assert!(!settings.wrap_around);
         assert_eq!(settings.max_attempts, 10);
+        assert_eq!(settings.max_backtracks, 1000);
         assert_eq!(settings.max_grid_growth, 10);
-        assert_eq!(settings.prefer_overlap, false);
+        assert_eq!(settings.overlap, OverlapPolicy::Prefer);
+        assert_eq!(settings.seed, None);
+        assert_eq!(settings.filler_alphabet, "abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(settings.filler_mode, FillerMode::Uniform);
+        assert_eq!(settings.normalization, Normalization::default());
+        assert_eq!(settings.duplicates, DuplicatePolicy::Warn);
+        assert!(settings.unique_words);
+        assert!(settings.pinned_words.is_empty());
+        assert_eq!(settings.hidden_message, None);
+        assert_eq!(settings.forbidden_words, default_forbidden_words());
 
         settings.set_width(20);
         settings.set_height(20);
@@ -76,4 +343,97 @@ mod tests {
         assert_eq!(settings_2.width, 20);
         assert_eq!(settings_2.height, 20);
     }
+
+    #[test]
+    fn test_overlap_policy_names() {
+        assert_eq!(
+            OverlapPolicy::from_name("disallow"),
+            Some(OverlapPolicy::Disallow)
+        );
+        assert_eq!(
+            OverlapPolicy::from_name(OverlapPolicy::Avoid.name()),
+            Some(OverlapPolicy::Avoid)
+        );
+        assert_eq!(OverlapPolicy::from_name("sometimes"), None);
+    }
+
+    #[test]
+    fn test_duplicate_policy_names() {
+        assert_eq!(
+            DuplicatePolicy::from_name("drop"),
+            Some(DuplicatePolicy::Drop)
+        );
+        assert_eq!(
+            DuplicatePolicy::from_name(DuplicatePolicy::Fail.name()),
+            Some(DuplicatePolicy::Fail)
+        );
+        assert_eq!(DuplicatePolicy::from_name("ignore"), None);
+    }
+
+    #[test]
+    fn test_max_size() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.max_size(), None);
+
+        settings.max_width = Some(12);
+        assert_eq!(settings.max_size(), Some((12, i32::MAX)));
+
+        settings.max_height = Some(15);
+        assert_eq!(settings.max_size(), Some((12, 15)));
+
+        settings.fixed_size = true;
+        assert_eq!(settings.max_size(), Some((10, 10)));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Settings::default().validate(), Ok(()));
+
+        let settings = Settings {
+            orientations: vec![],
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.validate(),
+            Err(WordSearchError::InvalidSettings(String::from(
+                "at least one orientation is required"
+            )))
+        );
+
+        let settings = Settings {
+            width: 0,
+            ..Default::default()
+        };
+        assert_eq!(settings.validate().unwrap_err().code(), "INVALID_SETTINGS");
+
+        let settings = Settings {
+            max_width: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(settings.validate().unwrap_err().code(), "INVALID_SETTINGS");
+
+        let settings = Settings {
+            target_density: Some(1.5),
+            ..Default::default()
+        };
+        assert_eq!(settings.validate().unwrap_err().code(), "INVALID_SETTINGS");
+
+        let settings = Settings {
+            mask: Some(Mask::new(vec![vec![true], vec![true, true]])),
+            ..Default::default()
+        };
+        assert_eq!(settings.validate().unwrap_err().code(), "INVALID_SETTINGS");
+
+        let settings = Settings {
+            mask: Some(Mask::full(0, 3)),
+            ..Default::default()
+        };
+        assert_eq!(settings.validate().unwrap_err().code(), "INVALID_SETTINGS");
+
+        let settings = Settings {
+            filler_alphabet: String::from("AB C"),
+            ..Default::default()
+        };
+        assert_eq!(settings.validate().unwrap_err().code(), "INVALID_SETTINGS");
+    }
 }
@@ -0,0 +1,58 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeAttempts {
+    // The width of the grid tried
+    pub width: i32,
+    // The height of the grid tried
+    pub height: i32,
+    // The number of times the words were placed in a grid of this size
+    pub attempts: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Statistics {
+    // The attempts made at every grid size, in the order they were tried
+    pub attempts: Vec<SizeAttempts>,
+    // The number of placements undone to make room for other words
+    pub backtracks: i32,
+    // The number of times the grid grew
+    pub grid_growths: i32,
+    // The width of the final grid
+    pub width: i32,
+    // The height of the final grid
+    pub height: i32,
+    // Time taken to create the puzzle in milliseconds
+    pub elapsed_ms: f64,
+}
+
+impl Statistics {
+    pub fn add_attempts(&mut self, width: i32, height: i32, attempts: i32) {
+        self.attempts.push(SizeAttempts {
+            width,
+            height,
+            attempts,
+        });
+    }
+
+    // The number of attempts made across all grid sizes
+    pub fn total_attempts(&self) -> i32 {
+        self.attempts.iter().map(|size| size.attempts).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statistics() {
+        let mut statistics = Statistics::default();
+        assert_eq!(statistics.total_attempts(), 0);
+
+        statistics.add_attempts(5, 5, 10);
+        statistics.add_attempts(6, 6, 3);
+        assert_eq!(statistics.attempts.len(), 2);
+        assert_eq!(statistics.attempts[1].width, 6);
+        assert_eq!(statistics.attempts[1].attempts, 3);
+        assert_eq!(statistics.total_attempts(), 13);
+    }
+}
@@ -1,5 +1,4 @@
-use js_sys::Math::{floor, random};
-use wasm_bindgen::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub fn set_panic_hook() {
     // When the `console_error_panic_hook` feature is enabled, we can call the
@@ -12,9 +11,55 @@ pub fn set_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
-#[wasm_bindgen]
-pub fn rand_char() -> char {
-    const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
-    let random_index: usize = floor(random() * LETTERS.len() as f64) as usize;
-    LETTERS.chars().nth(random_index).unwrap()
+// Splits a word into the letters placed in the grid cells, a letter is a
+// grapheme cluster such as `é`, `क्ष` or `👍🏽` and not a single `char`
+pub fn letters(word: &str) -> Vec<&str> {
+    word.graphemes(true).collect()
+}
+
+// Copy of the grid with every cell in lowercase, for case-insensitive searches
+pub fn lowercase_grid(puzzle: &[Vec<String>]) -> Vec<Vec<String>> {
+    puzzle
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.to_lowercase()).collect())
+        .collect()
+}
+
+// Current time in milliseconds, used to time the puzzle creation
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+// Current time in milliseconds, used to time the puzzle creation
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+pub fn now_ms() -> f64 {
+    // There is no clock without a JS host
+    0.0
+}
+
+// Current time in milliseconds, used to time the puzzle creation
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now_ms() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64() * 1000.0)
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters() {
+        assert_eq!(letters("café"), vec!["c", "a", "f", "é"]);
+        // `e` followed by a combining acute accent
+        assert_eq!(letters("cafe\u{301}").len(), 4);
+        assert_eq!(letters("straße").len(), 6);
+        assert_eq!(letters("한국어"), vec!["한", "국", "어"]);
+        assert_eq!(letters("क्षमा").len(), 2);
+        assert_eq!(letters("👍🏽👍").len(), 2);
+    }
 }
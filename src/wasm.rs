@@ -0,0 +1,445 @@
+use crate::error::WordSearchError;
+use crate::filler::FillerMode;
+use crate::mask::Mask;
+use crate::normalize::{Accents, CaseFolding, Normalization};
+use crate::orientations::Orientation;
+use crate::random::RandomSource;
+use crate::settings::{DuplicatePolicy, OverlapPolicy, PinnedWord, Settings};
+use crate::WordSearch;
+use js_sys::Math::random;
+use js_sys::{Array, Object, Reflect, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+// Randomness taken from the JS host's `Math.random`
+//
+// **Note:** Puzzles created with this source cannot be reproduced
+pub struct JsRandom;
+
+impl RandomSource for JsRandom {
+    fn next_u64(&mut self) -> u64 {
+        (random() * (1u64 << 53) as f64) as u64
+    }
+}
+
+// Errors are thrown as a JS `Error` with a `code` property
+// matching `WordSearchError::code`
+//
+// ```js
+// try { generator.create(); } catch (e) { if (e.code === "GRID_GROWTH_EXHAUSTED") { ... } }
+// ```
+impl From<WordSearchError> for JsValue {
+    fn from(error: WordSearchError) -> JsValue {
+        let js_error = js_sys::Error::new(&error.to_string());
+        js_error.set_name("WordSearchError");
+        // Setting a property on a fresh error object cannot fail
+        let _ = set(&js_error, "code", &JsValue::from(error.code()));
+        js_error.into()
+    }
+}
+
+// Word search generator exported to JavaScript
+//
+// ```js
+// const generator = new WordSearchGenerator(["hello", "world"], { width: 8, seed: 42 });
+// const { grid, placements, warnings } = generator.create();
+// ```
+#[wasm_bindgen]
+pub struct WordSearchGenerator {
+    word_search: WordSearch,
+}
+
+#[wasm_bindgen]
+impl WordSearchGenerator {
+    // Takes an array of words and an optional options object holding
+    // the `Settings` fields in camel case, plus `maskImage`:
+    //
+    // - `width`, `height`, `fixedSize` and `bestEffort`
+    // - `mask`: ASCII art with `#` for cells and `.` for holes,
+    //   or rows of booleans
+    // - `maskImage`: the bytes of a PBM or PNG silhouette,
+    //   scaled to `width` x `height`
+    // - `targetDensity`, `maxWidth`, `maxHeight` and `wrapAround`
+    // - `orientations`: an array of orientation names
+    // - `maxAttempts`, `maxBacktracks` and `maxGridGrowth`
+    // - `overlap`: `"prefer"`, `"avoid"` or `"disallow"`
+    // - `seed`: a number
+    // - `fillerAlphabet`: a string of letters
+    // - `fillerMode`: `"uniform"`, `"matchWords"`, `"english"`,
+    //   `"french"`, `"german"` or `"spanish"`
+    // - `normalization`: `{ case, stripSpaces, stripPunctuation,
+    //   accents, expandLigatures }`
+    // - `duplicates`: `"drop"`, `"warn"` or `"fail"`
+    // - `uniqueWords`
+    // - `pinnedWords`: an array of `{ word, x, y, orientation }`
+    // - `hiddenMessage`: a string
+    // - `forbiddenWords`: an array replacing the default blocklist
+    #[wasm_bindgen(constructor)]
+    pub fn new(words: Array, options: JsValue) -> Result<WordSearchGenerator, JsValue> {
+        let words = words
+            .iter()
+            .map(|word| {
+                word.as_string()
+                    .ok_or_else(|| JsValue::from(js_sys::TypeError::new("Words must be strings")))
+            })
+            .collect::<Result<Vec<String>, JsValue>>()?;
+        let settings = settings_from_options(&options)?;
+        Ok(WordSearchGenerator {
+            word_search: WordSearch::with_settings(&words, settings)?,
+        })
+    }
+
+    // Creates the puzzle and returns `{ grid, placements, wordBank,
+    // wordsNotPlaced, wordsNotUsed, warnings, fillPercentage, statistics,
+    // seed }`, the seed is a decimal string and each placement's `word` is
+    // the word as given while `gridWord` is the normalized form placed in
+    // the grid, `cells` lists the `{ x, y }` of each letter which follows
+    // words wrapping around the grid, cells outside the mask are empty
+    // strings
+    pub fn create(&mut self) -> Result<JsValue, JsValue> {
+        let word_search = self.word_search.create()?;
+
+        let grid = Array::new();
+        for row in word_search.puzzle.puzzle.iter() {
+            grid.push(
+                &row.iter()
+                    .map(|c| JsValue::from(c.as_str()))
+                    .collect::<Array>(),
+            );
+        }
+
+        let mut words: Vec<&String> = word_search.placed_words.keys().collect();
+        words.sort();
+        let placements = Array::new();
+        for word in words {
+            let location = &word_search.placed_words[word];
+            let placement = Object::new();
+            set(&placement, "word", &JsValue::from(word.as_str()))?;
+            set(
+                &placement,
+                "gridWord",
+                &JsValue::from(location.word.as_str()),
+            )?;
+            set(&placement, "x", &JsValue::from(location.x))?;
+            set(&placement, "y", &JsValue::from(location.y))?;
+            set(
+                &placement,
+                "orientation",
+                &JsValue::from(location.orientation.name()),
+            )?;
+            set(&placement, "overlap", &JsValue::from(location.overlap))?;
+            let cells = Array::new();
+            for cell in location.cells() {
+                let position = Object::new();
+                set(&position, "x", &JsValue::from(cell.x))?;
+                set(&position, "y", &JsValue::from(cell.y))?;
+                cells.push(&position);
+            }
+            set(&placement, "cells", &cells)?;
+            placements.push(&placement);
+        }
+
+        let statistics = Object::new();
+        let attempts = Array::new();
+        for size in word_search.statistics.attempts.iter() {
+            let size_attempts = Object::new();
+            set(&size_attempts, "width", &JsValue::from(size.width))?;
+            set(&size_attempts, "height", &JsValue::from(size.height))?;
+            set(&size_attempts, "attempts", &JsValue::from(size.attempts))?;
+            attempts.push(&size_attempts);
+        }
+        set(&statistics, "attempts", &attempts)?;
+        set(
+            &statistics,
+            "backtracks",
+            &JsValue::from(word_search.statistics.backtracks),
+        )?;
+        set(
+            &statistics,
+            "gridGrowths",
+            &JsValue::from(word_search.statistics.grid_growths),
+        )?;
+        set(
+            &statistics,
+            "width",
+            &JsValue::from(word_search.statistics.width),
+        )?;
+        set(
+            &statistics,
+            "height",
+            &JsValue::from(word_search.statistics.height),
+        )?;
+        set(
+            &statistics,
+            "elapsedMs",
+            &JsValue::from(word_search.statistics.elapsed_ms),
+        )?;
+
+        let result = Object::new();
+        set(&result, "grid", &grid)?;
+        set(&result, "placements", &placements)?;
+        set(&result, "wordBank", &strings(&word_search.word_bank()))?;
+        set(
+            &result,
+            "wordsNotPlaced",
+            &strings(&word_search.puzzle.words_not_placed),
+        )?;
+        set(
+            &result,
+            "wordsNotUsed",
+            &strings(&word_search.puzzle.words_not_used),
+        )?;
+        set(&result, "warnings", &strings(&word_search.puzzle.warnings))?;
+        set(
+            &result,
+            "fillPercentage",
+            &JsValue::from(word_search.grid_fill_percentage),
+        )?;
+        set(
+            &result,
+            "seed",
+            &JsValue::from(word_search.seed().to_string()),
+        )?;
+        Ok(result.into())
+    }
+
+    // The seed used by the generator, pass it back in the options
+    // to recreate the same puzzle
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> String {
+        self.word_search.seed().to_string()
+    }
+}
+
+fn set(target: &Object, key: &str, value: &JsValue) -> Result<(), JsValue> {
+    Reflect::set(target, &JsValue::from(key), value).map(|_| ())
+}
+
+fn get(options: &JsValue, key: &str) -> Result<Option<JsValue>, JsValue> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(None);
+    }
+    let value = Reflect::get(options, &JsValue::from(key))?;
+    if value.is_undefined() || value.is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(value))
+    }
+}
+
+fn get_number(options: &JsValue, key: &str) -> Result<Option<f64>, JsValue> {
+    match get(options, key)? {
+        Some(value) => match value.as_f64() {
+            Some(number) => Ok(Some(number)),
+            None => {
+                Err(js_sys::TypeError::new(&format!("Option `{}` must be a number", key)).into())
+            }
+        },
+        None => Ok(None),
+    }
+}
+
+fn strings(values: &[String]) -> Array {
+    values
+        .iter()
+        .map(|value| JsValue::from(value.as_str()))
+        .collect()
+}
+
+fn settings_from_options(options: &JsValue) -> Result<Settings, JsValue> {
+    let mut settings = Settings::default();
+    if let Some(width) = get_number(options, "width")? {
+        settings.set_width(width as i32);
+    }
+    if let Some(height) = get_number(options, "height")? {
+        settings.set_height(height as i32);
+    }
+    if let Some(fixed_size) = get(options, "fixedSize")? {
+        settings.fixed_size = fixed_size.is_truthy();
+    }
+    if let Some(mask) = get(options, "mask")? {
+        settings.mask = Some(match mask.as_string() {
+            Some(art) => Mask::from_ascii(&art)?,
+            // Rows of cells, truthy where a letter can go
+            None => Mask::new(
+                Array::from(&mask)
+                    .iter()
+                    .map(|row| {
+                        Array::from(&row)
+                            .iter()
+                            .map(|cell| cell.is_truthy())
+                            .collect()
+                    })
+                    .collect(),
+            ),
+        });
+    }
+    if let Some(image) = get(options, "maskImage")? {
+        let data = Uint8Array::new(&image).to_vec();
+        settings.mask = Some(Mask::from_image(&data, settings.width, settings.height)?);
+    }
+    if let Some(best_effort) = get(options, "bestEffort")? {
+        settings.best_effort = best_effort.is_truthy();
+    }
+    if let Some(target_density) = get_number(options, "targetDensity")? {
+        settings.target_density = Some(target_density);
+    }
+    if let Some(max_width) = get_number(options, "maxWidth")? {
+        settings.max_width = Some(max_width as i32);
+    }
+    if let Some(max_height) = get_number(options, "maxHeight")? {
+        settings.max_height = Some(max_height as i32);
+    }
+    if let Some(wrap_around) = get(options, "wrapAround")? {
+        settings.wrap_around = wrap_around.is_truthy();
+    }
+    if let Some(max_attempts) = get_number(options, "maxAttempts")? {
+        settings.max_attempts = max_attempts as i32;
+    }
+    if let Some(max_backtracks) = get_number(options, "maxBacktracks")? {
+        settings.max_backtracks = max_backtracks as i32;
+    }
+    if let Some(max_grid_growth) = get_number(options, "maxGridGrowth")? {
+        settings.max_grid_growth = max_grid_growth as i32;
+    }
+    if let Some(overlap) = get(options, "overlap")? {
+        settings.overlap = overlap
+            .as_string()
+            .and_then(|name| OverlapPolicy::from_name(&name))
+            .ok_or_else(|| {
+                js_sys::TypeError::new(&format!("Unknown overlap policy {:?}", overlap))
+            })?;
+    }
+    if let Some(seed) = get(options, "seed")? {
+        // Seeds above 2^53 lose precision as numbers, so they
+        // can also be passed as a decimal string
+        settings.seed = Some(
+            seed.as_f64()
+                .map(|number| number as u64)
+                .or_else(|| seed.as_string().and_then(|text| text.parse().ok()))
+                .ok_or_else(|| {
+                    js_sys::TypeError::new("Option `seed` must be a number or a decimal string")
+                })?,
+        );
+    }
+    if let Some(filler_alphabet) = get(options, "fillerAlphabet")? {
+        settings.filler_alphabet = filler_alphabet
+            .as_string()
+            .ok_or_else(|| js_sys::TypeError::new("Option `fillerAlphabet` must be a string"))?;
+    }
+    if let Some(filler_mode) = get(options, "fillerMode")? {
+        settings.filler_mode = filler_mode
+            .as_string()
+            .and_then(|name| FillerMode::from_name(&name))
+            .ok_or_else(|| {
+                js_sys::TypeError::new(&format!("Unknown filler mode {:?}", filler_mode))
+            })?;
+    }
+    if let Some(duplicates) = get(options, "duplicates")? {
+        settings.duplicates = duplicates
+            .as_string()
+            .and_then(|name| DuplicatePolicy::from_name(&name))
+            .ok_or_else(|| {
+                js_sys::TypeError::new(&format!("Unknown duplicates policy {:?}", duplicates))
+            })?;
+    }
+    if let Some(unique_words) = get(options, "uniqueWords")? {
+        settings.unique_words = unique_words.is_truthy();
+    }
+    if let Some(pinned_words) = get(options, "pinnedWords")? {
+        settings.pinned_words = Array::from(&pinned_words)
+            .iter()
+            .map(|pin| pinned_word_from_options(&pin))
+            .collect::<Result<Vec<PinnedWord>, JsValue>>()?;
+    }
+    if let Some(hidden_message) = get(options, "hiddenMessage")? {
+        settings.hidden_message =
+            Some(hidden_message.as_string().ok_or_else(|| {
+                js_sys::TypeError::new("Option `hiddenMessage` must be a string")
+            })?);
+    }
+    if let Some(forbidden_words) = get(options, "forbiddenWords")? {
+        settings.forbidden_words = Array::from(&forbidden_words)
+            .iter()
+            .map(|word| {
+                word.as_string().ok_or_else(|| {
+                    JsValue::from(js_sys::TypeError::new("Forbidden words must be strings"))
+                })
+            })
+            .collect::<Result<Vec<String>, JsValue>>()?;
+    }
+    if let Some(normalization) = get(options, "normalization")? {
+        settings.normalization = normalization_from_options(&normalization)?;
+    }
+    if let Some(orientations) = get(options, "orientations")? {
+        settings.orientations = Array::from(&orientations)
+            .iter()
+            .map(|name| {
+                name.as_string()
+                    .and_then(|name| Orientation::from_name(&name))
+                    .ok_or_else(|| {
+                        JsValue::from(js_sys::TypeError::new(&format!(
+                            "Unknown orientation {:?}",
+                            name
+                        )))
+                    })
+            })
+            .collect::<Result<Vec<Orientation>, JsValue>>()?;
+    }
+    Ok(settings)
+}
+
+// Reads a pinned word from `{ word, x, y, orientation }`
+fn pinned_word_from_options(options: &JsValue) -> Result<PinnedWord, JsValue> {
+    let word = get(options, "word")?
+        .and_then(|word| word.as_string())
+        .ok_or_else(|| js_sys::TypeError::new("Pinned words need a `word` string"))?;
+    let orientation = get(options, "orientation")?
+        .and_then(|name| name.as_string())
+        .and_then(|name| Orientation::from_name(&name))
+        .ok_or_else(|| js_sys::TypeError::new("Pinned words need a known `orientation`"))?;
+    Ok(PinnedWord::new(
+        &word,
+        get_number(options, "x")?.unwrap_or(0.0) as i32,
+        get_number(options, "y")?.unwrap_or(0.0) as i32,
+        orientation,
+    ))
+}
+
+fn normalization_from_options(options: &JsValue) -> Result<Normalization, JsValue> {
+    let mut normalization = Normalization::default();
+    if let Some(case) = get(options, "case")? {
+        normalization.case = match case.as_string().as_deref() {
+            Some("preserve") => CaseFolding::Preserve,
+            Some("lower") => CaseFolding::Lower,
+            Some("upper") => CaseFolding::Upper,
+            _ => {
+                return Err(
+                    js_sys::TypeError::new(&format!("Unknown case folding {:?}", case)).into(),
+                )
+            }
+        };
+    }
+    if let Some(strip_spaces) = get(options, "stripSpaces")? {
+        normalization.strip_spaces = strip_spaces.is_truthy();
+    }
+    if let Some(strip_punctuation) = get(options, "stripPunctuation")? {
+        normalization.strip_punctuation = strip_punctuation.is_truthy();
+    }
+    if let Some(accents) = get(options, "accents")? {
+        normalization.accents = match accents.as_string().as_deref() {
+            Some("preserve") => Accents::Preserve,
+            Some("strip") => Accents::Strip,
+            _ => {
+                return Err(js_sys::TypeError::new(&format!(
+                    "Unknown accents option {:?}",
+                    accents
+                ))
+                .into())
+            }
+        };
+    }
+    if let Some(expand_ligatures) = get(options, "expandLigatures")? {
+        normalization.expand_ligatures = expand_ligatures.is_truthy();
+    }
+    Ok(normalization)
+}
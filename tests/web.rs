@@ -3,6 +3,7 @@
 #![cfg(target_arch = "wasm32")]
 
 extern crate wasm_bindgen_test;
+use js_sys::{Array, Object, Reflect};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::*;
 use wordsearch_wasm::*;
@@ -34,8 +35,8 @@ fn test_word_search() {
     ];
     const width: i32 = 7;
     const height: i32 = 7;
-    let mut word_search = WordSearch::new(&words, width, height);
-    let output = word_search.create();
+    let mut word_search = WordSearch::new(&words, width, height).unwrap();
+    let output = word_search.create().unwrap();
 
     let mut i = 0;
     while i < width {
@@ -43,10 +44,70 @@ fn test_word_search() {
         let mut j = 0;
         while j < height {
             row += " ";
-            row += output.puzzle.puzzle[i as usize][j as usize].to_string().as_str();
+            row += output.puzzle.puzzle[i as usize][j as usize]
+                .to_string()
+                .as_str();
             j += 1;
         }
         console_log!("{}", row);
         i += 1;
     }
 }
+
+#[wasm_bindgen_test]
+fn test_placed_words() {
+    let words: Vec<String> = vec![
+        String::from("hello"),
+        String::from("world"),
+        String::from("dart"),
+        String::from("rust"),
+        String::from("wasm"),
+    ];
+    let mut word_search = WordSearch::new(&words, 7, 7).unwrap();
+    let output = word_search.create().unwrap();
+
+    assert_eq!(output.placed_words.len(), words.len());
+    assert!(output.unplaced_words.is_empty());
+    for word in words.iter() {
+        let location = &output.placed_words[word];
+        let next_orientation = get_orientation(&location.orientation);
+        for (i, letter) in word.chars().enumerate() {
+            let position = next_orientation(location.x, location.y, i as i32);
+            assert_eq!(
+                output.puzzle.puzzle[position.y as usize][position.x as usize],
+                letter.to_string()
+            );
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_word_search_generator() {
+    let words: Array = vec!["hello", "world", "dart", "rust", "wasm"]
+        .into_iter()
+        .map(JsValue::from)
+        .collect();
+    let options = Object::new();
+    Reflect::set(&options, &"width".into(), &7.into()).unwrap();
+    Reflect::set(&options, &"height".into(), &7.into()).unwrap();
+    Reflect::set(&options, &"seed".into(), &42.into()).unwrap();
+    let mut generator = WordSearchGenerator::new(words, options.into()).unwrap();
+    let result = generator.create().unwrap();
+
+    let grid = Array::from(&Reflect::get(&result, &"grid".into()).unwrap());
+    assert_eq!(grid.length(), 7);
+    let placements = Array::from(&Reflect::get(&result, &"placements".into()).unwrap());
+    assert_eq!(placements.length(), 5);
+    let seed = Reflect::get(&result, &"seed".into()).unwrap();
+    assert_eq!(seed.as_string(), Some(String::from("42")));
+}
+
+#[wasm_bindgen_test]
+fn test_word_search_generator_error_code() {
+    let error = match WordSearchGenerator::new(Array::new(), JsValue::UNDEFINED) {
+        Ok(_) => panic!("An empty word list must be rejected"),
+        Err(error) => error,
+    };
+    let code = Reflect::get(&error, &"code".into()).unwrap();
+    assert_eq!(code.as_string(), Some(String::from("EMPTY_WORD_LIST")));
+}